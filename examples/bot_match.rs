@@ -0,0 +1,59 @@
+//! Four bots playing a full game against each other, printing the score after every round.
+//!
+//! ```text
+//! cargo run --example bot_match
+//! ```
+
+extern crate spades;
+extern crate uuid;
+
+use std::io::{self, Write};
+use spades::{Card, Game, GameTransition, Rank, State, Suit, Team, TransitionSuccess};
+use uuid::Uuid;
+
+/// Bids one trick per spade above ten and per ace of another suit, and at least one.
+fn bot_bet(hand: &[Card]) -> i32 {
+    let tricks = hand.iter()
+        .filter(|card| (card.suit == Suit::Spade && card.rank as u8 > Rank::Ten as u8) || card.rank == Rank::Ace)
+        .count() as i32;
+    tricks.max(1)
+}
+
+/// Plays the lowest ranked card it is allowed to.
+fn bot_card(g: &Game) -> Card {
+    let mut hand = g.get_current_hand().unwrap().clone();
+    hand.sort_by_key(|card| card.rank as u8);
+    hand.into_iter().find(|card| g.check(&GameTransition::Card(card.clone())).is_ok()).unwrap()
+}
+
+/// Plays one game to `max_points`, writing a line per round to `output`.
+pub fn run<W: Write>(max_points: i32, output: &mut W) -> io::Result<Game> {
+    let mut g = Game::new(Uuid::new_v4(), [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()], max_points).unwrap();
+    g.play(GameTransition::Start).unwrap();
+
+    let mut round = 1;
+    while *g.get_state() != State::Completed {
+        let transition = match *g.get_state() {
+            State::Betting(_) => GameTransition::Bet(bot_bet(g.get_current_hand().unwrap())),
+            _ => GameTransition::Card(bot_card(&g)),
+        };
+        let success = g.play(transition).unwrap();
+        if success == TransitionSuccess::GameOver || (success == TransitionSuccess::Trick && *g.get_state() == State::Betting(0)) {
+            writeln!(output, "Round {}: team A {}, team B {}", round, g.get_score(Team::A).unwrap(), g.get_score(Team::B).unwrap())?;
+            round += 1;
+        }
+    }
+
+    let outcome = g.get_winner().unwrap();
+    let [a, b] = outcome.scores;
+    match outcome.winner {
+        Some(team) => writeln!(output, "{} wins {} to {}", team, a.max(b), a.min(b))?,
+        None => writeln!(output, "Tied at {}", a)?,
+    }
+    Ok(g)
+}
+
+fn main() {
+    let stdout = io::stdout();
+    run(500, &mut stdout.lock()).unwrap();
+}
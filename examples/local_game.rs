@@ -0,0 +1,81 @@
+//! Four players sharing one terminal. Each turn prints the current hand, then reads a bet during betting or the
+//! index of a card to play during tricks.
+//!
+//! ```text
+//! cargo run --example local_game
+//! ```
+
+extern crate spades;
+extern crate uuid;
+
+use std::io::{self, BufRead, Write};
+use spades::{Game, GameTransition, Rank, State, Team};
+use uuid::Uuid;
+
+const SEATS: [&str; 4] = ["A", "B", "C", "D"];
+
+/// Plays one game reading moves from `input`, until the game is completed or `input` runs out.
+pub fn run<R: BufRead, W: Write>(input: R, output: &mut W) -> io::Result<Game> {
+    let ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+    let mut g = Game::new(Uuid::new_v4(), ids, 500).unwrap();
+    g.play(GameTransition::Start).unwrap();
+
+    let mut lines = input.lines();
+    while *g.get_state() != State::Completed {
+        let seat = ids.iter().position(|id| id == g.get_current_player_id().unwrap()).unwrap();
+        let hand = g.reveal_hand(ids[seat]).unwrap().clone();
+        writeln!(output, "Player {}, your hand:", SEATS[seat])?;
+        for (i, card) in hand.iter().enumerate() {
+            writeln!(output, "  {:>2}: {} {}", i, card.emoji(), card.long_name())?;
+        }
+
+        let transition = if let State::Betting(_) = *g.get_state() {
+            write!(output, "Bet (0-13): ")?;
+            output.flush()?;
+            let line = match lines.next() {
+                Some(line) => line?,
+                None => break,
+            };
+            match line.trim().parse() {
+                Ok(bet) => GameTransition::Bet(bet),
+                Err(_) => {
+                    writeln!(output, "Please enter a number.")?;
+                    continue;
+                },
+            }
+        } else {
+            if let Ok(cards) = g.get_current_trick_cards() {
+                let played: Vec<String> = cards.iter().filter(|card| card.rank != Rank::Blank).map(|card| card.long_name()).collect();
+                writeln!(output, "Played so far: {}", played.join(", "))?;
+            }
+            write!(output, "Card to play (0-{}): ", hand.len() - 1)?;
+            output.flush()?;
+            let line = match lines.next() {
+                Some(line) => line?,
+                None => break,
+            };
+            match line.trim().parse::<usize>().ok().and_then(|i| hand.get(i)) {
+                Some(card) => GameTransition::Card(card.clone()),
+                None => {
+                    writeln!(output, "Please enter the index of a card in your hand.")?;
+                    continue;
+                },
+            }
+        };
+
+        if let Err(e) = g.play(transition) {
+            writeln!(output, "{}", e)?;
+        }
+    }
+
+    if *g.get_state() == State::Completed {
+        writeln!(output, "Final score: team A {}, team B {}", g.get_score(Team::A).unwrap(), g.get_score(Team::B).unwrap())?;
+    }
+    Ok(g)
+}
+
+fn main() {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    run(stdin.lock(), &mut stdout.lock()).unwrap();
+}
@@ -3,6 +3,9 @@ extern crate rand;
 use self::rand::{thread_rng, Rng};
 use std::fmt;
 use std::cmp::Ordering;
+use std::convert::TryFrom;
+use result::ParseCardError;
+use game_state::HandSortOrder;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Suit {
@@ -24,6 +27,20 @@ impl fmt::Debug for Suit {
         }
     }
 }
+
+impl Suit {
+    /// Returns the plural English name, e.g. `"spades"`, or an em dash for `Blank`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Suit::Blank => "\u{2014}",
+            Suit::Club => "clubs",
+            Suit::Diamond => "diamonds",
+            Suit::Heart => "hearts",
+            Suit::Spade => "spades",
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Rank {
     Blank = 0,
@@ -62,6 +79,28 @@ impl fmt::Debug for Rank {
     }
 }
 
+impl Rank {
+    /// Returns the English name, e.g. `"queen"`, or an em dash for `Blank`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rank::Blank => "\u{2014}",
+            Rank::Two => "two",
+            Rank::Three => "three",
+            Rank::Four => "four",
+            Rank::Five => "five",
+            Rank::Six => "six",
+            Rank::Seven => "seven",
+            Rank::Eight => "eight",
+            Rank::Nine => "nine",
+            Rank::Ten => "ten",
+            Rank::Jack => "jack",
+            Rank::Queen => "queen",
+            Rank::King => "king",
+            Rank::Ace => "ace",
+        }
+    }
+}
+
 /// Intuitive card struct. Comparisons are made according to alphabetical order, ascending.
 #[derive(Clone, PartialEq, Eq)]
 pub struct Card {
@@ -75,6 +114,85 @@ impl fmt::Debug for Card {
     }
 }
 
+impl Card {
+    /// Returns the card's stable encoding in `0..52`, `(suit - 1) * 13 + (rank - 2)`: clubs are `0..13`, diamonds
+    /// `13..26`, hearts `26..39` and spades `39..52`, each from two up to ace. The order matches `Ord` for `Card`.
+    ///
+    /// Returns `ParseCardError::Blank` if the suit or rank is `Blank`.
+    pub fn to_u8(&self) -> Result<u8, ParseCardError> {
+        if self.suit == Suit::Blank || self.rank == Rank::Blank {
+            return Err(ParseCardError::Blank);
+        }
+        Ok((self.suit as u8 - 1) * 13 + (self.rank as u8 - 2))
+    }
+
+    /// Returns the English name, e.g. `"queen of spades"`, or an em dash if the suit or rank is `Blank`.
+    pub fn long_name(&self) -> String {
+        if self.suit == Suit::Blank || self.rank == Rank::Blank {
+            return "\u{2014}".to_string();
+        }
+        format!("{} of {}", self.rank.name(), self.suit.name())
+    }
+
+    /// Returns the card's character from the Unicode playing cards block, e.g. `'\u{1F0AD}'` for the queen of spades,
+    /// or an em dash if the suit or rank is `Blank`.
+    pub fn emoji(&self) -> char {
+        let suit = match self.suit {
+            Suit::Blank => return '\u{2014}',
+            Suit::Spade => 0x1F0A0,
+            Suit::Heart => 0x1F0B0,
+            Suit::Diamond => 0x1F0C0,
+            Suit::Club => 0x1F0D0,
+        };
+        // The block places the knight between the jack and the queen, and the ace first.
+        let rank = match self.rank {
+            Rank::Blank => return '\u{2014}',
+            Rank::Ace => 0x1,
+            Rank::Queen => 0xD,
+            Rank::King => 0xE,
+            rank => rank as u32,
+        };
+        ::std::char::from_u32(suit + rank).unwrap()
+    }
+
+    /// Inverse of [`Card::to_u8`](struct.Card.html#method.to_u8), returns `ParseCardError::InvalidEncoding` for values
+    /// of 52 and above.
+    pub fn from_u8(value: u8) -> Result<Card, ParseCardError> {
+        let suit = match value / 13 {
+            0 => Suit::Club,
+            1 => Suit::Diamond,
+            2 => Suit::Heart,
+            3 => Suit::Spade,
+            _ => return Err(ParseCardError::InvalidEncoding(value)),
+        };
+        let rank = match value % 13 {
+            0 => Rank::Two,
+            1 => Rank::Three,
+            2 => Rank::Four,
+            3 => Rank::Five,
+            4 => Rank::Six,
+            5 => Rank::Seven,
+            6 => Rank::Eight,
+            7 => Rank::Nine,
+            8 => Rank::Ten,
+            9 => Rank::Jack,
+            10 => Rank::Queen,
+            11 => Rank::King,
+            _ => Rank::Ace,
+        };
+
+        Ok(Card { suit, rank })
+    }
+}
+
+impl TryFrom<Card> for u8 {
+    type Error = ParseCardError;
+
+    fn try_from(card: Card) -> Result<u8, ParseCardError> {
+        card.to_u8()
+    }
+}
+
 impl Ord for Card {
     fn cmp(&self, other: &Card) -> Ordering {
         ((self.suit as u64) * 15 + (self.rank as u64)).cmp(&(((other.suit as u64)* 15) + (other.rank as u64)))
@@ -83,7 +201,7 @@ impl Ord for Card {
 
 impl PartialOrd for Card {
     fn partial_cmp(&self, other: &Card) -> Option<Ordering> {
-        Some(((self.suit as u64) * 15 + (self.rank as u64)).cmp(&(((other.suit as u64)* 15) + (other.rank as u64))))
+        Some(self.cmp(other))
     }
 }
 
@@ -100,19 +218,18 @@ pub fn get_trick_winner(index: usize, others: &[Card ; 4]) -> usize {
     let mut winning_index = index;
     let mut max_card = &others[index];
 
-    for i in 0..4 {
-        let other = &others[i];
+    for (i, other) in others.iter().enumerate() {
         if other.suit == max_card.suit {
             if other.rank as u8  > max_card.rank as u8 {
-                max_card = &other;
+                max_card = other;
                 winning_index = i;
             }
         } else if other.suit == Suit::Spade {
-            max_card = &other;
+            max_card = other;
             winning_index = i;
         }
     }
-    return winning_index;
+    winning_index
 }
 
 
@@ -144,12 +261,12 @@ pub fn new_deck() -> Vec<Card> {
     let mut cards = Vec::new();
     for s in &suits {
         for r in &ranks {
-            cards.push(Card {suit:s.clone(), rank:r.clone()});
+            cards.push(Card {suit: *s, rank: *r});
         }
     }
     shuffle(&mut cards);
 
-    return cards;
+    cards
 }
 
 /// Returns an array of `Blank` suited and ranked cards.
@@ -162,8 +279,17 @@ pub fn new_pot() -> [Card; 4] {
     ]
 }
 
+/// Sorts a hand in place, see [`HandSortOrder`](enum.HandSortOrder.html).
+pub fn sort_hand(cards: &mut [Card], order: HandSortOrder) {
+    match order {
+        HandSortOrder::BySuit => cards.sort(),
+        HandSortOrder::BySuitDescending => cards.sort_by(|a, b| b.cmp(a)),
+        HandSortOrder::ByRank => cards.sort_by_key(|card| (card.rank as u8, card.suit as u8)),
+    }
+}
+
 /// Shuffles a `Vector` of cards in place, see [`rand::thread_rng::shuffle`](https://docs.rs/rand/0.5.4/rand/trait.Rng.html#method.shuffle).
-pub fn shuffle(cards: &mut Vec<Card>) {
+pub fn shuffle(cards: &mut [Card]) {
     let mut rng = thread_rng();
     rng.shuffle(cards);
 }
@@ -175,11 +301,11 @@ pub fn deal_four_players(cards: &mut Vec<Card>) -> Vec<Vec<Card>> {
     let mut hands = vec![vec![], vec![], vec![], vec![]];
 
     let mut i = 0;
-    while cards.len() > 0 {
-        &hands[i].push(cards.pop().unwrap());
+    while let Some(card) = cards.pop() {
+        hands[i].push(card);
         i = (i + 1) % 4;
     }
 
-    return hands;
+    hands
 }
 
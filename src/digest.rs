@@ -0,0 +1,119 @@
+use cards::Card;
+use game_state::{State, MisdealRule, CompletionReason};
+use super::Game;
+
+/// 64 bit FNV-1a, used instead of `std::hash` so digests stay the same across platforms and Rust releases.
+struct Digest(u64);
+
+impl Digest {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn u8(&mut self, value: u8) {
+        self.write(&[value]);
+    }
+
+    fn bool(&mut self, value: bool) {
+        self.u8(value as u8);
+    }
+
+    fn i32(&mut self, value: i32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn usize(&mut self, value: usize) {
+        self.write(&(value as u64).to_le_bytes());
+    }
+
+    fn card(&mut self, card: &Card) {
+        self.u8(card.to_u8().unwrap_or(0xff));
+    }
+
+    fn cards(&mut self, cards: &[Card]) {
+        self.usize(cards.len());
+        for card in cards {
+            self.card(card);
+        }
+    }
+}
+
+/// See [`Game::state_digest`](struct.Game.html#method.state_digest).
+pub fn state_digest(game: &Game) -> u64 {
+    let mut digest = Digest(0xcbf2_9ce4_8422_2325);
+
+    match game.state {
+        State::NotStarted => digest.u8(0),
+        State::Betting(rotation_status) => { digest.u8(1); digest.usize(rotation_status); },
+        State::Trick(rotation_status) => { digest.u8(2); digest.usize(rotation_status); },
+        State::RoundOver => digest.u8(3),
+        State::Completed => digest.u8(4),
+    }
+    digest.usize(game.current_player_index);
+    digest.u8(game.leading_suit as u8);
+    digest.u8(match game.completion_reason {
+        None => 0,
+        Some(CompletionReason::Points) => 1,
+    });
+
+    for player in [&game.player_a, &game.player_b, &game.player_c, &game.player_d].iter() {
+        let mut hand = player.hand.clone();
+        hand.sort();
+        digest.cards(&hand);
+        digest.bool(player.hand_revealed);
+    }
+    let mut deck = game.deck.clone();
+    deck.sort();
+    digest.cards(&deck);
+    digest.usize(game.hands_played.len());
+    for pot in &game.hands_played {
+        digest.cards(pot);
+    }
+    digest.usize(game.trick_leaders.len());
+    for leader in &game.trick_leaders {
+        digest.usize(*leader);
+    }
+
+    let scoring = &game.scoring;
+    digest.i32(scoring.config.max_points);
+    digest.bool(scoring.config.manual_round_advance);
+    digest.u8(match scoring.config.misdeal_rule {
+        MisdealRule::None => 0,
+        MisdealRule::NoSpades => 1,
+        MisdealRule::NoFaceCards => 2,
+    });
+    for team in [&scoring.team_a, &scoring.team_b].iter() {
+        for tricks in team.current_round_tricks_won.iter() {
+            digest.i32(*tricks);
+        }
+        digest.i32(team.bags);
+        digest.i32(team.cumulative_points);
+    }
+    digest.bool(scoring.in_betting_stage);
+    digest.usize(scoring.bets_placed.len());
+    for bets in &scoring.bets_placed {
+        for bet in bets.iter() {
+            digest.i32(*bet);
+        }
+    }
+    digest.usize(scoring.blind_nils.len());
+    for blind_nils in &scoring.blind_nils {
+        for blind in blind_nils.iter() {
+            digest.bool(*blind);
+        }
+    }
+    digest.bool(scoring.is_over);
+    digest.usize(scoring.round);
+    digest.usize(scoring.trick);
+    for nil in scoring.nil_check.iter() {
+        digest.bool(*nil);
+    }
+    for tricks in scoring.tricks_won.iter() {
+        digest.i32(*tricks);
+    }
+
+    digest.0
+}
@@ -1,3 +1,5 @@
+use std::fmt;
+
 /// Current game stage, field of `Game`. 
 /// 
 /// The `Betting` and `Trick` variants have a `usize` value between 0
@@ -5,10 +7,100 @@
 /// respectively.
 /// 
 /// **Example:** `State::Trick(2)` means the game is in the card playing stage, and two players have played their cards.
+///
+/// `RoundOver` is only reached when manual round advance is enabled, see
+/// [`Game::set_manual_round_advance`](struct.Game.html#method.set_manual_round_advance).
 #[derive(Debug, PartialEq)]
 pub enum State {
     NotStarted,
     Betting(usize),
     Trick(usize),
+    RoundOver,
     Completed
 }
+
+/// The variant of a [`State`](enum.State.html) without its rotation count, see [`State::kind`](enum.State.html#method.kind).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateKind {
+    NotStarted,
+    Betting,
+    Trick,
+    RoundOver,
+    Completed
+}
+
+impl State {
+    pub fn kind(&self) -> StateKind {
+        match self {
+            State::NotStarted => StateKind::NotStarted,
+            State::Betting(_) => StateKind::Betting,
+            State::Trick(_) => StateKind::Trick,
+            State::RoundOver => StateKind::RoundOver,
+            State::Completed => StateKind::Completed,
+        }
+    }
+}
+
+/// The two partnerships. Team `A` is the first and third seats, team `B` the second and fourth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Team {
+    A,
+    B
+}
+
+impl Team {
+    /// Returns the team sitting in `seat`. Panics if `seat` is above 3.
+    pub fn of_seat(seat: usize) -> Team {
+        assert!(seat < 4, "Seats are numbered 0 to 3");
+        match seat {
+            0 | 2 => Team::A,
+            _ => Team::B,
+        }
+    }
+
+    /// Returns the seat across from `seat`. Panics if `seat` is above 3.
+    pub fn partner_of(seat: usize) -> usize {
+        assert!(seat < 4, "Seats are numbered 0 to 3");
+        (seat + 2) % 4
+    }
+}
+
+impl fmt::Display for Team {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Team::A => write!(f, "Team A"),
+            Team::B => write!(f, "Team B"),
+        }
+    }
+}
+
+/// Order a player's hand is kept in, set with [`Game::set_hand_sort`](struct.Game.html#method.set_hand_sort).
+///
+/// * `BySuit` groups clubs, diamonds, hearts then spades, each from two up to ace
+/// * `BySuitDescending` groups spades, hearts, diamonds then clubs, each from ace down to two
+/// * `ByRank` goes from twos up to aces, clubs to spades within a rank
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandSortOrder {
+    BySuit,
+    BySuitDescending,
+    ByRank
+}
+
+/// Why a game reached `State::Completed`, see [`GameOutcome`](struct.GameOutcome.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionReason {
+    /// A team reached the game's maximum points at the end of a round.
+    Points
+}
+
+/// House rule allowing a player to declare a misdeal instead of betting, set with
+/// [`Game::set_misdeal_rule`](struct.Game.html#method.set_misdeal_rule).
+///
+/// * `NoSpades` allows a misdeal when the player holds no spades
+/// * `NoFaceCards` allows a misdeal when the player holds no jacks, queens or kings
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MisdealRule {
+    None,
+    NoSpades,
+    NoFaceCards
+}
@@ -14,7 +14,7 @@
 //!     uuid::Uuid::new_v4(), 
 //!     uuid::Uuid::new_v4(), 
 //!     uuid::Uuid::new_v4()], 
-//!     500);
+//!     500).unwrap();
 //! 
 //! 
 //! g.play(GameTransition::Start);
@@ -39,37 +39,108 @@
 
 extern crate uuid;
 
+
 mod scoring;
 mod game_state;
 mod cards;
 mod result;
+mod review;
+mod notation;
+mod digest;
+#[cfg(feature = "admin-debug")]
+mod locate;
 
 #[cfg(test)]
 mod tests;
 
+/// Runs the snippets in the README as doctests.
+#[cfg(doctest)]
+#[doc = include_str!("../readme.md")]
+pub struct ReadmeDoctests;
+
 use uuid::Uuid;
 pub use result::*;
 pub use cards::*;
 pub use game_state::*;
+pub use scoring::{ProjectedOutcome, GameOutcome, BidRecord, RoundRecord};
+pub use review::ReviewFlag;
+#[cfg(feature = "admin-debug")]
+pub use locate::CardLocation;
 
 /// The primary way to interface with a spades game. Used as an argument to [Game::play](struct.Game.html#method.play).
+#[derive(Debug, Clone, PartialEq)]
 pub enum GameTransition {
     Bet(i32),
     Card(Card),
     Start,
+    NextRound,
+    DeclareMisdeal,
+    BlindNil,
+}
+
+/// The variant of a [`GameTransition`](enum.GameTransition.html) without its argument, see
+/// [`GameTransition::kind`](enum.GameTransition.html#method.kind).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionKind {
+    Bet,
+    Card,
+    Start,
+    NextRound,
+    DeclareMisdeal,
+    BlindNil,
+}
+
+impl GameTransition {
+    pub fn kind(&self) -> TransitionKind {
+        match self {
+            GameTransition::Bet(_) => TransitionKind::Bet,
+            GameTransition::Card(_) => TransitionKind::Card,
+            GameTransition::Start => TransitionKind::Start,
+            GameTransition::NextRound => TransitionKind::NextRound,
+            GameTransition::DeclareMisdeal => TransitionKind::DeclareMisdeal,
+            GameTransition::BlindNil => TransitionKind::BlindNil,
+        }
+    }
+}
+
+/// A bet that passed validation, applied by `Game::apply_bet`.
+struct ValidatedBet {
+    bet: i32,
+    blind: bool,
+    rotation_status: usize,
+}
+
+/// A card play that passed validation, applied by `Game::apply_card`.
+struct ValidatedPlay {
+    card_index: usize,
+    leading_suit: Suit,
+    rotation_status: usize,
+}
+
+enum ValidatedTransition {
+    Bet(ValidatedBet),
+    Card(ValidatedPlay),
+    Start,
+    NextRound,
+    Misdeal,
 }
 
 #[derive(Debug)]
 struct Player{
     id: Uuid,
-    hand: Vec<Card>
+    hand: Vec<Card>,
+    /// Set by `Game::reveal_hand`, cleared on every deal.
+    hand_revealed: bool,
+    hand_sort: HandSortOrder
 }
 
 impl Player {
     pub fn new(id: Uuid) -> Player {
         Player {
-            id: id,
-            hand: vec![]
+            id,
+            hand: vec![],
+            hand_revealed: false,
+            hand_sort: HandSortOrder::BySuit
         }
     }
 }
@@ -83,8 +154,10 @@ pub struct Game {
     current_player_index: usize,
     deck: Vec<cards::Card>,
     hands_played: Vec<[cards::Card; 4]>,
-    bets_placed: Vec<[i32; 4]>,
+    trick_leaders: Vec<usize>,
     leading_suit: Suit,
+    team_names: [Option<String>; 2],
+    completion_reason: Option<CompletionReason>,
     player_a: Player,
     player_b: Player,
     player_c: Player,
@@ -92,21 +165,80 @@ pub struct Game {
 }
 
 impl Game {
-    pub fn new(id: Uuid, player_ids: [Uuid; 4], max_points: i32) -> Game {
-        Game {
-            id: id,
+    /// Returns `BuildError::DuplicatePlayerIds` if the same `Uuid` is given for more than one seat.
+    pub fn new(id: Uuid, player_ids: [Uuid; 4], max_points: i32) -> Result<Game, BuildError> {
+        for i in 0..4 {
+            if player_ids[i + 1..].contains(&player_ids[i]) {
+                return Err(BuildError::DuplicatePlayerIds);
+            }
+        }
+
+        Ok(Game {
+            id,
             state: State::NotStarted,
             scoring: scoring::Scoring::new(max_points),
             hands_played: vec![new_pot()],
-            bets_placed: vec![[0;4]],
+            trick_leaders: vec![],
             deck: cards::new_deck(),
             current_player_index: 0,
             leading_suit: Suit::Blank,
+            team_names: [None, None],
+            completion_reason: None,
             player_a: Player::new(player_ids[0]),
             player_b: Player::new(player_ids[1]),
             player_c: Player::new(player_ids[2]),
             player_d: Player::new(player_ids[3]),
+        })
+    }
+
+    /// When enabled, finishing the 13th trick of a round moves the game to `State::RoundOver` instead of dealing the
+    /// next round, which then waits for a [`GameTransition::NextRound`](enum.GameTransition.html#variant.NextRound).
+    /// Disabled by default.
+    pub fn set_manual_round_advance(&mut self, manual_round_advance: bool) {
+        self.scoring.config.manual_round_advance = manual_round_advance;
+    }
+
+    /// Sets which hands allow the current bettor to declare a
+    /// [`GameTransition::DeclareMisdeal`](enum.GameTransition.html#variant.DeclareMisdeal). `MisdealRule::None` by default.
+    pub fn set_misdeal_rule(&mut self, misdeal_rule: MisdealRule) {
+        self.scoring.config.misdeal_rule = misdeal_rule;
+    }
+
+    /// Sets the order the player's hand is kept in, from now on and for every later deal. `HandSortOrder::BySuit` by
+    /// default. Returns `GetError::InvalidUuid` if the game does not contain a player with the given `Uuid`.
+    pub fn set_hand_sort(&mut self, player_id: Uuid, order: HandSortOrder) -> Result<(), GetError> {
+        let player = self.player_by_id_mut(player_id)?;
+        player.hand_sort = order;
+        cards::sort_hand(&mut player.hand, order);
+        Ok(())
+    }
+
+    /// Names `team`, or clears its name with `None`. Names are trimmed, and must then be between 1 and 32 characters.
+    pub fn set_team_name(&mut self, team: Team, name: Option<String>) -> Result<(), NameError> {
+        let name = match name {
+            Some(name) => {
+                let name = name.trim();
+                if name.is_empty() {
+                    return Err(NameError::Empty);
+                }
+                if name.chars().count() > 32 {
+                    return Err(NameError::TooLong);
+                }
+                Some(name.to_string())
+            },
+            None => None,
+        };
+
+        match team {
+            Team::A => self.team_names[0] = name,
+            Team::B => self.team_names[1] = name,
         }
+        Ok(())
+    }
+
+    /// Returns the names of teams `A` and `B`, in that order.
+    pub fn get_team_names(&self) -> &[Option<String>; 2] {
+        &self.team_names
     }
 
     pub fn get_id(&self) -> &Uuid {
@@ -118,39 +250,61 @@ impl Game {
         &self.state
     }
 
-    pub fn get_team_a_score(&self) ->  Result<&i32, GetError> {
-        match (&self.state, self.current_player_index) {
-            (State::NotStarted, _) => {Err(GetError::GameNotStarted)},
-            _ => {Ok(&self.scoring.team_a.cumulative_points)}
+    /// Returns the team's cumulative points, available in every state after `Start` (the final score once `Completed`).
+    pub fn get_score(&self, team: Team) -> Result<&i32, GetError> {
+        match team {
+            Team::A => self.started_scoring().map(|s| &s.team_a.cumulative_points),
+            Team::B => self.started_scoring().map(|s| &s.team_b.cumulative_points),
         }
     }
 
-    pub fn get_team_b_score(&self) ->  Result<&i32, GetError> {
-        match (&self.state, self.current_player_index) {
-            (State::NotStarted, _) => {Err(GetError::GameNotStarted)},
-            _ => {Ok(&self.scoring.team_b.cumulative_points)}
+    /// Returns the team's current bags, available in every state after `Start` (the final count once `Completed`).
+    pub fn get_bags(&self, team: Team) -> Result<&i32, GetError> {
+        match team {
+            Team::A => self.started_scoring().map(|s| &s.team_a.bags),
+            Team::B => self.started_scoring().map(|s| &s.team_b.bags),
         }
     }
 
-    pub fn get_team_a_bags(&self) -> Result<&i32, GetError> {
+    fn started_scoring(&self) -> Result<&scoring::Scoring, GetError> {
         match self.state {
             State::NotStarted => {Err(GetError::GameNotStarted)},
-            _ => {Ok(&self.scoring.team_a.bags)}
+            _ => {Ok(&self.scoring)}
         }
     }
 
+    /// Returns how many more bags the team can take before the next 100 point penalty, between 1 and 10. A round that
+    /// takes a team past the threshold more than once costs 100 points each time.
+    pub fn bags_remaining_until_penalty(&self, team: Team) -> Result<u8, GetError> {
+        self.get_bags(team).map(|bags| (scoring::BAG_PENALTY_THRESHOLD - bags) as u8)
+    }
+
+    #[deprecated(since="1.0.0", note="Please use `get_score(Team::A)`")]
+    pub fn get_team_a_score(&self) -> Result<&i32, GetError> {
+        self.get_score(Team::A)
+    }
+
+    #[deprecated(since="1.0.0", note="Please use `get_score(Team::B)`")]
+    pub fn get_team_b_score(&self) -> Result<&i32, GetError> {
+        self.get_score(Team::B)
+    }
+
+    #[deprecated(since="1.0.0", note="Please use `get_bags(Team::A)`")]
+    pub fn get_team_a_bags(&self) -> Result<&i32, GetError> {
+        self.get_bags(Team::A)
+    }
+
+    #[deprecated(since="1.0.0", note="Please use `get_bags(Team::B)`")]
     pub fn get_team_b_bags(&self) -> Result<&i32, GetError> {
-        match self.state {
-            State::NotStarted => {Err(GetError::GameNotStarted)},
-            _ => {Ok(&self.scoring.team_b.bags)}
-        }
+        self.get_bags(Team::B)
     }
-    
+
     /// Returns `GetError` when the current game is not in the Betting or Trick stages.
     pub fn get_current_player_id(&self) -> Result<&Uuid, GetError>{
         match (&self.state, self.current_player_index) {
             (State::NotStarted, _) => {Err(GetError::GameNotStarted)},
             (State::Completed, _) => {Err(GetError::GameCompleted)},
+            (State::RoundOver, _) => {Err(GetError::RoundOver)},
             (State::Betting(_), 0) | (State::Trick(_), 0) => Ok(&self.player_a.id),
             (State::Betting(_), 1) | (State::Trick(_), 1) => Ok(&self.player_b.id),
             (State::Betting(_), 2) | (State::Trick(_), 2) => Ok(&self.player_c.id),
@@ -159,32 +313,77 @@ impl Game {
         }
     }
 
-    /// Returns a `GetError::InvalidUuid` if the game does not contain a player with the given `Uuid`.
+    /// Returns a `GetError::InvalidUuid` if the game does not contain a player with the given `Uuid`. Fetching a hand
+    /// here does not count as the player seeing it, so they may still bid blind nil. Use
+    /// [`reveal_hand`](struct.Game.html#method.reveal_hand) when showing the hand to the player.
     pub fn get_hand_by_player_id(&self, player_id: Uuid) -> Result<&Vec<Card>, GetError> {
-        if player_id == self.player_a.id {
-            return Ok(&self.player_a.hand);
+        self.player_by_id(player_id).map(|player| &player.hand)
+    }
+
+    /// Returns the hand of the player whose turn it is. Like
+    /// [`get_hand_by_player_id`](struct.Game.html#method.get_hand_by_player_id), this does not count as the player
+    /// seeing it; use [`reveal_hand`](struct.Game.html#method.reveal_hand) for that.
+    pub fn get_current_hand(&self) -> Result<&Vec<Card>, GetError> {
+        self.current_player().map(|player| &player.hand)
+    }
+
+    /// Returns the player's hand and records that they have seen it, so they can no longer bid blind nil until the next
+    /// deal. Use this rather than [`get_hand_by_player_id`](struct.Game.html#method.get_hand_by_player_id) when showing
+    /// the hand to the player. Returns a `GetError::InvalidUuid` if the game does not contain a player with the given
+    /// `Uuid`.
+    pub fn reveal_hand(&mut self, player_id: Uuid) -> Result<&Vec<Card>, GetError> {
+        let player = self.player_by_id_mut(player_id)?;
+        player.hand_revealed = true;
+        Ok(&player.hand)
+    }
+
+    /// Returns whether the player may still bid [`GameTransition::BlindNil`](enum.GameTransition.html#variant.BlindNil):
+    /// the game is in the betting stage, they have not bet yet and their hand has not been revealed since the deal.
+    pub fn blind_nil_available(&self, player_id: Uuid) -> Result<bool, GetError> {
+        let player = self.player_by_id(player_id)?;
+        let seat = self.players().iter().position(|p| p.id == player_id).unwrap();
+        match self.state {
+            State::NotStarted => {Err(GetError::GameNotStarted)},
+            State::Completed => {Err(GetError::GameCompleted)},
+            State::Betting(bets_placed) => {Ok(seat >= bets_placed && !player.hand_revealed)},
+            _ => {Ok(false)}
         }
-        if player_id == self.player_a.id {
-            return Ok(&self.player_a.hand);
-        }        
-        if player_id == self.player_a.id {
-            return Ok(&self.player_a.hand);
-        }        
-        if player_id == self.player_a.id {
-            return Ok(&self.player_a.hand);
+    }
+
+    fn players(&self) -> [&Player; 4] {
+        [&self.player_a, &self.player_b, &self.player_c, &self.player_d]
+    }
+
+    fn player_by_id(&self, player_id: Uuid) -> Result<&Player, GetError> {
+        match self.players().iter().find(|p| p.id == player_id) {
+            Some(player) => Ok(player),
+            None => Err(GetError::InvalidUuid),
         }
+    }
 
-        return Err(GetError::InvalidUuid);
+    fn player_by_id_mut(&mut self, player_id: Uuid) -> Result<&mut Player, GetError> {
+        if player_id == self.player_a.id {
+            Ok(&mut self.player_a)
+        } else if player_id == self.player_b.id {
+            Ok(&mut self.player_b)
+        } else if player_id == self.player_c.id {
+            Ok(&mut self.player_c)
+        } else if player_id == self.player_d.id {
+            Ok(&mut self.player_d)
+        } else {
+            Err(GetError::InvalidUuid)
+        }
     }
-    
-    pub fn get_current_hand(&self) -> Result<&Vec<Card>, GetError> {
+
+    fn current_player(&self) -> Result<&Player, GetError> {
         match (&self.state, self.current_player_index) {
             (State::NotStarted, _) => {Err(GetError::GameNotStarted)},
             (State::Completed, _) => {Err(GetError::GameCompleted)},
-            (State::Betting(_), 0) | (State::Trick(_), 0) => Ok(&self.player_a.hand),
-            (State::Betting(_), 1) | (State::Trick(_), 1) => Ok(&self.player_b.hand),
-            (State::Betting(_), 2) | (State::Trick(_), 2) => Ok(&self.player_c.hand),
-            (State::Betting(_), 3) | (State::Trick(_), 3) => Ok(&self.player_d.hand),
+            (State::RoundOver, _) => {Err(GetError::RoundOver)},
+            (State::Betting(_), 0) | (State::Trick(_), 0) => Ok(&self.player_a),
+            (State::Betting(_), 1) | (State::Trick(_), 1) => Ok(&self.player_b),
+            (State::Betting(_), 2) | (State::Trick(_), 2) => Ok(&self.player_c),
+            (State::Betting(_), 3) | (State::Trick(_), 3) => Ok(&self.player_d),
             _ => {Err(GetError::Unknown)}
         }
     }
@@ -193,44 +392,148 @@ impl Game {
         match &self.state {
             State::NotStarted => {Err(GetError::GameNotStarted)},
             State::Completed => {Err(GetError::GameCompleted)},
+            State::RoundOver => {Err(GetError::RoundOver)},
             State::Trick(_) => Ok(&self.leading_suit),
             _ => {Err(GetError::Unknown)}
         }
     }
 
-    /// Returns an array with (only if in the trick stage).
+    /// Returns, for each seat, whether that player has already bet this round (only if in the betting stage). Betting
+    /// always opens with the first seat, so `State::Betting(2)` marks the first two seats.
+    pub fn bet_status_by_seat(&self) -> Result<[bool; 4], GetError> {
+        match self.state {
+            State::NotStarted => {Err(GetError::GameNotStarted)},
+            State::Completed => {Err(GetError::GameCompleted)},
+            State::Betting(bets_placed) => {
+                let mut status = [false; 4];
+                for seat in status.iter_mut().take(bets_placed) {
+                    *seat = true;
+                }
+                Ok(status)
+            },
+            State::RoundOver => {Err(GetError::RoundOver)},
+            State::Trick(_) => {Err(GetError::Unknown)},
+        }
+    }
+
+    /// Returns each team's score at the end of the round if both teams take exactly the tricks they bet from here on,
+    /// and whether that would end the game (only if in the trick stage). A team that has already won more tricks than
+    /// it bet keeps them as bags, and nil bets still standing are assumed to be made. This is for display only.
+    pub fn projected_outcome(&self) -> Result<ProjectedOutcome, GetError> {
+        match self.state {
+            State::NotStarted => {Err(GetError::GameNotStarted)},
+            State::Completed => {Err(GetError::GameCompleted)},
+            State::RoundOver => {Err(GetError::RoundOver)},
+            State::Trick(_) => {Ok(self.scoring.projected_outcome())},
+            _ => {Err(GetError::Unknown)}
+        }
+    }
+
+    /// Flags plays that contradict a void the same seat showed earlier in the round, for post-game review. The flags
+    /// are inferred from the completed tricks only and have no effect on the game.
+    pub fn review_flags(&self) -> Vec<ReviewFlag> {
+        review::review_flags(&self.hands_played, &self.trick_leaders)
+    }
+
+    /// Finds where `card` currently is, for support investigations, or `None` for `Blank` cards. A card played in an
+    /// earlier round is reported at its most recent trick until it is dealt again. Requires the `admin-debug` feature.
+    #[cfg(feature = "admin-debug")]
+    pub fn locate_card(&self, card: &Card) -> Option<CardLocation> {
+        locate::locate_card(self, card)
+    }
+
+    /// Writes the game as plain text, one line per bid round, trick and round score, for example:
+    ///
+    /// ```text
+    /// MaxPoints 500
+    /// Round 1
+    /// Bids A 3 B 0 C 4 D 3
+    /// Trick 1 A:2C B:KC C:10C D:AC
+    /// ...
+    /// Trick 13 B:AS C:9S D:2H A:QS
+    /// Score A 71 B -70
+    /// ```
+    ///
+    /// Plays in each trick start with the seat that led. Only completed rounds are written.
+    pub fn to_notation(&self) -> String {
+        notation::to_notation(self)
+    }
+
+    /// Rebuilds a game from [`to_notation`](struct.Game.html#method.to_notation) by dealing each round the cards its
+    /// tricks show every seat holding and replaying the bids and plays, which must all be legal and reproduce the
+    /// recorded scores. The game and player ids are newly generated.
+    pub fn from_notation(notation: &str) -> Result<Game, NotationError> {
+        notation::from_notation(notation)
+    }
+
+    /// Returns a hash of everything that affects play: the stage, turn, hands, which hands have been revealed, played
+    /// cards, bets, scores, bags, round and trick counters and the house rules. The game and player ids, team names,
+    /// the order cards are kept in each hand and the order of the undealt deck (which is reshuffled before every deal)
+    /// are left out. The hash is stable across platforms and releases, so it can be compared between processes.
+    pub fn state_digest(&self) -> u64 {
+        digest::state_digest(self)
+    }
+
+    /// Whether `other` has the same [`state_digest`](struct.Game.html#method.state_digest).
+    pub fn equivalent(&self, other: &Game) -> bool {
+        self.state_digest() == other.state_digest()
+    }
+
+    /// Returns the cards played so far in the current trick (only if in the trick stage), or the round's last trick
+    /// while it waits for `NextRound`.
     pub fn get_current_trick_cards(&self) -> Result<&[cards::Card; 4], GetError> {
         match self.state {
             State::NotStarted => {Err(GetError::GameNotStarted)},
             State::Completed => {Err(GetError::GameCompleted)},
             State::Betting(_) => {Err(GetError::GameCompleted)},
-            State::Trick(_) => {Ok(self.hands_played.last().unwrap())},
+            State::Trick(_) | State::RoundOver => {Ok(self.hands_played.last().unwrap())},
         }
     }
 
+    /// Returns `GetError::InvalidSeat` for seats above 3. Will be removed in 2.0.0.
     #[deprecated(since="1.0.0", note="Please use `get_current_hand` or `get_hand_by_player_id`")]
     pub fn get_hand(&self, player: usize) -> Result<&Vec<Card>, GetError> {
+        if self.state == State::NotStarted {
+            return Err(GetError::GameNotStarted);
+        }
         match player {
-            0 => Ok(&self.player_a.hand),
-            1 => Ok(&self.player_b.hand),
-            2 => Ok(&self.player_c.hand),
-            3 => Ok(&self.player_d.hand),
-            _ => Ok(&self.player_d.hand),
+            0..=3 => Ok(&self.players()[player].hand),
+            _ => Err(GetError::InvalidSeat),
         }
     }
 
+    /// Returns a record of each completed round, oldest first.
+    pub fn round_history(&self) -> &[RoundRecord] {
+        &self.scoring.rounds
+    }
+
+    /// Returns the player's bid, tricks taken and whether the bid was made in each completed round, oldest first.
+    /// Returns `GetError::InvalidUuid` if the game does not contain a player with the given `Uuid`.
+    pub fn bid_history(&self, player_id: Uuid) -> Result<Vec<BidRecord>, GetError> {
+        match self.players().iter().position(|p| p.id == player_id) {
+            Some(seat) => Ok(self.scoring.bid_history(seat)),
+            None => Err(GetError::InvalidUuid),
+        }
+    }
+
+    /// Returns the winning team, final scores and why the game ended, or `GetError::GameNotCompleted` while it is
+    /// still being played.
+    pub fn get_winner(&self) -> Result<GameOutcome, GetError> {
+        match (&self.state, self.completion_reason) {
+            (State::Completed, Some(reason)) => {Ok(self.scoring.outcome(reason))},
+            (State::Completed, None) => {Err(GetError::Unknown)},
+            _ => {Err(GetError::GameNotCompleted)}
+        }
+    }
+
+    /// Returns the ids of the winning team's players, or `GetError::Unknown` if the game ended in a tie. Will be
+    /// removed in 2.0.0.
+    #[deprecated(since="1.0.0", note="Please use `get_winner`")]
     pub fn get_winner_ids(&self) -> Result<(&Uuid, &Uuid), GetError> {
-        match self.state {
-            State::Completed => {
-                if self.scoring.team_a.cumulative_points <= self.scoring.team_b.cumulative_points {
-                    return Ok((&self.player_a.id, &self.player_c.id));
-                } else {
-                    return Ok((&self.player_b.id, &self.player_d.id));
-                }
-            },
-            _ => {
-                Err(GetError::GameNotCompleted)
-            }
+        match self.get_winner()?.winner {
+            Some(Team::A) => {Ok((&self.players()[0].id, &self.players()[Team::partner_of(0)].id))},
+            Some(Team::B) => {Ok((&self.players()[1].id, &self.players()[Team::partner_of(1)].id))},
+            None => {Err(GetError::Unknown)}
         }
     }
 
@@ -239,105 +542,203 @@ impl Game {
     /// internally. The order of `GameTransition` arguments should be:
     /// 
     /// Start -> Bet * 4 -> Card * 13 -> Bet * 4 -> Card * 13 -> Bet * 4 -> ...
+    ///
+    /// With manual round advance enabled, each round of 13 tricks is followed by `NextRound` before betting resumes:
+    ///
+    /// Start -> Bet * 4 -> Card * 13 -> NextRound -> Bet * 4 -> Card * 13 -> NextRound -> ...
+    ///
+    /// During betting, the player whose turn it is may send `DeclareMisdeal` instead of a bet if their hand satisfies
+    /// the [`MisdealRule`](enum.MisdealRule.html). The round is then re-dealt and betting starts over.
+    ///
+    /// Bets must be between 0 and 13, inclusive. A bet of 0 is nil, worth 100 points if the player takes no tricks and
+    /// -100 otherwise. Instead of betting, a player whose hand has not been revealed since the deal may send
+    /// `BlindNil`, a nil bet worth 200 points or -200.
+    ///
+    /// The result of each transition in each stage, given a legal argument (a bet in range, a card that follows suit,
+    /// a hand that satisfies the misdeal rule, a blind nil before the hand is revealed):
+    ///
+    #[doc = include_str!("transitions.md")]
     pub fn play(&mut self, entry: GameTransition) -> Result<TransitionSuccess, TransitionError> {
-        match entry {
-            GameTransition::Bet(bet) => {
-                match self.state {
-                    State::NotStarted => {
-                        return Err(TransitionError::NotStarted); 
-                    },
-                    State::Trick(_rotation_status) => {
-                        return Err(TransitionError::BetInTrickStage);
-                    },
-                    State::Completed => {
-                        return Err(TransitionError::CompletedGame);
-                    },
-                    State::Betting(rotation_status) => {
-                        self.scoring.add_bet(self.current_player_index,bet);
-                        if rotation_status == 3 {
-                            self.scoring.bet();
-                            self.state = State::Trick((rotation_status + 1) % 4);
-                            self.current_player_index = 0;
-                            return Ok(TransitionSuccess::BetComplete);
-                        } else {
-                            self.current_player_index = (self.current_player_index + 1) % 4;
-                            self.state = State::Betting((rotation_status + 1) % 4);
-                        }
+        let validated = self.validate(&entry)?;
+        Ok(self.apply(validated))
+    }
 
-                        return Ok(TransitionSuccess::Bet);
-                    },
-                };
-            },
-            GameTransition::Card(card) => {
-                match self.state {
-                    State::NotStarted => {
-                        return Err(TransitionError::NotStarted); 
-                    },
-                    State::Completed => {
-                        return Err(TransitionError::CompletedGame);
-                    },
-                    State::Betting(_rotation_status) => {
-                        return Err(TransitionError::CardInBettingStage)
-                    },
-                    State::Trick(rotation_status) => {
-                        {
-                            let player_hand = &mut match self.current_player_index {
-                                0 => &mut self.player_a,
-                                1 => &mut self.player_b,
-                                2 => &mut self.player_c,
-                                3 => &mut self.player_d,
-                                _ => &mut self.player_d,
-                            }.hand;
-
-                            if !player_hand.contains(&card) {
-                                return Err(TransitionError::CardNotInHand);
-                            }
-                            let leading_suit = self.leading_suit;
-                            if rotation_status == 0 {
-                                self.leading_suit = card.suit;
-                            }
-                            if self.leading_suit != card.suit && player_hand.iter().any(|ref x| x.suit == leading_suit) {
-                                return Err(TransitionError::CardIncorrectSuit);
-                            }
-
-                            let card_index = player_hand.iter().position(|x| x == &card).unwrap();
-                            self.deck.push(player_hand.remove(card_index));
-                        }
-                        
-                        self.hands_played.last_mut().unwrap()[self.current_player_index] = card;
-                        
-                        if rotation_status == 3 {
-                            let winner = self.scoring.trick(self.current_player_index, self.hands_played.last().unwrap());
-                            if self.scoring.is_over {
-                                self.state = State::Completed;
-                                return Ok(TransitionSuccess::GameOver);
-                            }
-                            if self.scoring.in_betting_stage {
-                                self.current_player_index = 0;
-                                self.state = State::Betting((rotation_status + 1) % 4);
-                                self.deal_cards();
-                            } else {
-                                self.current_player_index = winner;
-                                self.state = State::Trick((rotation_status + 1) % 4);
-                                self.hands_played.push(new_pot());
-                            }
-                            return Ok(TransitionSuccess::Trick);
-                        } else {
-                            self.current_player_index = (self.current_player_index + 1) % 4;
-                            self.state = State::Trick((rotation_status + 1) % 4);
-                            return Ok(TransitionSuccess::PlayCard);
-                        }
-                    }
-                };
+    /// Returns the error [`play`](struct.Game.html#method.play) would return for `entry`, without changing the game.
+    pub fn check(&self, entry: &GameTransition) -> Result<(), TransitionError> {
+        self.validate(entry).map(|_| ())
+    }
+
+    fn validate(&self, entry: &GameTransition) -> Result<ValidatedTransition, TransitionError> {
+        match *entry {
+            GameTransition::Bet(bet) => self.validate_bet(bet).map(ValidatedTransition::Bet),
+            GameTransition::BlindNil => {
+                let mut validated = self.validate_bet(0)?;
+                if self.current_player().unwrap().hand_revealed {
+                    return Err(TransitionError::HandRevealed);
+                }
+                validated.blind = true;
+                Ok(ValidatedTransition::Bet(validated))
             },
+            GameTransition::Card(ref card) => self.validate_card(card).map(ValidatedTransition::Card),
             GameTransition::Start => {
                 if self.state != State::NotStarted {
                     return Err(TransitionError::AlreadyStarted);
                 }
+                Ok(ValidatedTransition::Start)
+            },
+            GameTransition::NextRound => {
+                match self.state {
+                    State::NotStarted => Err(TransitionError::NotStarted),
+                    State::Completed => Err(TransitionError::CompletedGame),
+                    State::Betting(_) | State::Trick(_) => Err(TransitionError::RoundNotOver),
+                    State::RoundOver => Ok(ValidatedTransition::NextRound),
+                }
+            },
+            GameTransition::DeclareMisdeal => {
+                match self.state {
+                    State::NotStarted => Err(TransitionError::NotStarted),
+                    State::Completed => Err(TransitionError::CompletedGame),
+                    State::RoundOver => Err(TransitionError::AwaitingNextRound),
+                    State::Trick(_) => Err(TransitionError::InvalidMisdeal),
+                    State::Betting(_) => {
+                        let player_hand = &self.current_player().unwrap().hand;
+                        let misdeal = match self.scoring.config.misdeal_rule {
+                            MisdealRule::None => false,
+                            MisdealRule::NoSpades => player_hand.iter().all(|x| x.suit != Suit::Spade),
+                            MisdealRule::NoFaceCards => player_hand.iter().all(|x| x.rank != Rank::Jack && x.rank != Rank::Queen && x.rank != Rank::King),
+                        };
+                        if !misdeal {
+                            return Err(TransitionError::InvalidMisdeal);
+                        }
+                        Ok(ValidatedTransition::Misdeal)
+                    },
+                }
+            },
+        }
+    }
+
+    fn validate_bet(&self, bet: i32) -> Result<ValidatedBet, TransitionError> {
+        let rotation_status = match self.state {
+            State::NotStarted => return Err(TransitionError::NotStarted),
+            State::Completed => return Err(TransitionError::CompletedGame),
+            State::RoundOver => return Err(TransitionError::AwaitingNextRound),
+            State::Trick(_) => return Err(TransitionError::BetInTrickStage),
+            State::Betting(rotation_status) => rotation_status,
+        };
+        if !(0..=13).contains(&bet) {
+            return Err(TransitionError::InvalidBet);
+        }
+
+        Ok(ValidatedBet { bet, blind: false, rotation_status })
+    }
+
+    fn validate_card(&self, card: &Card) -> Result<ValidatedPlay, TransitionError> {
+        let rotation_status = match self.state {
+            State::NotStarted => return Err(TransitionError::NotStarted),
+            State::Completed => return Err(TransitionError::CompletedGame),
+            State::RoundOver => return Err(TransitionError::AwaitingNextRound),
+            State::Betting(_) => return Err(TransitionError::CardInBettingStage),
+            State::Trick(rotation_status) => rotation_status,
+        };
+        let player_hand = &self.current_player().unwrap().hand;
+        let card_index = match player_hand.iter().position(|x| x == card) {
+            Some(card_index) => card_index,
+            None => return Err(TransitionError::CardNotInHand),
+        };
+        let leading_suit = if rotation_status == 0 { card.suit } else { self.leading_suit };
+        if card.suit != leading_suit && player_hand.iter().any(|x| x.suit == leading_suit) {
+            return Err(TransitionError::CardIncorrectSuit);
+        }
+
+        Ok(ValidatedPlay { card_index, leading_suit, rotation_status })
+    }
+
+    fn apply(&mut self, validated: ValidatedTransition) -> TransitionSuccess {
+        match validated {
+            ValidatedTransition::Bet(bet) => self.apply_bet(bet),
+            ValidatedTransition::Card(play) => self.apply_card(play),
+            ValidatedTransition::Start => {
+                self.deal_cards();
+                self.state = State::Betting(0);
+                TransitionSuccess::Start
+            },
+            ValidatedTransition::NextRound => {
+                self.hands_played.push(new_pot());
                 self.deal_cards();
                 self.state = State::Betting(0);
-                return Ok(TransitionSuccess::Start);
+                TransitionSuccess::NextRound
+            },
+            ValidatedTransition::Misdeal => {
+                self.deck.append(&mut self.player_a.hand);
+                self.deck.append(&mut self.player_b.hand);
+                self.deck.append(&mut self.player_c.hand);
+                self.deck.append(&mut self.player_d.hand);
+                self.scoring.clear_bets();
+                self.deal_cards();
+                self.current_player_index = 0;
+                self.state = State::Betting(0);
+                TransitionSuccess::Misdeal
+            },
+        }
+    }
+
+    fn apply_bet(&mut self, validated: ValidatedBet) -> TransitionSuccess {
+        let rotation_status = validated.rotation_status;
+        self.scoring.add_bet(self.current_player_index, validated.bet, validated.blind);
+        if rotation_status == 3 {
+            self.scoring.bet();
+            self.state = State::Trick((rotation_status + 1) % 4);
+            self.current_player_index = 0;
+            TransitionSuccess::BetComplete
+        } else {
+            self.current_player_index = (self.current_player_index + 1) % 4;
+            self.state = State::Betting((rotation_status + 1) % 4);
+            TransitionSuccess::Bet
+        }
+    }
+
+    fn apply_card(&mut self, validated: ValidatedPlay) -> TransitionSuccess {
+        let rotation_status = validated.rotation_status;
+        let card = match self.current_player_index {
+            0 => &mut self.player_a,
+            1 => &mut self.player_b,
+            2 => &mut self.player_c,
+            3 => &mut self.player_d,
+            _ => &mut self.player_d,
+        }.hand.remove(validated.card_index);
+
+        self.leading_suit = validated.leading_suit;
+        self.deck.push(card.clone());
+        self.hands_played.last_mut().unwrap()[self.current_player_index] = card;
+        
+        if rotation_status == 3 {
+            let leader = (self.current_player_index + 1) % 4;
+            self.trick_leaders.push(leader);
+            let winner = self.scoring.trick(leader, self.hands_played.last().unwrap());
+            if self.scoring.is_over {
+                self.completion_reason = Some(CompletionReason::Points);
+                self.state = State::Completed;
+                return TransitionSuccess::GameOver;
             }
+            if self.scoring.in_betting_stage {
+                self.current_player_index = 0;
+                if self.scoring.config.manual_round_advance {
+                    self.state = State::RoundOver;
+                } else {
+                    self.state = State::Betting((rotation_status + 1) % 4);
+                    self.hands_played.push(new_pot());
+                    self.deal_cards();
+                }
+            } else {
+                self.current_player_index = winner;
+                self.state = State::Trick((rotation_status + 1) % 4);
+                self.hands_played.push(new_pot());
+            }
+            TransitionSuccess::Trick
+        } else {
+            self.current_player_index = (self.current_player_index + 1) % 4;
+            self.state = State::Trick((rotation_status + 1) % 4);
+            TransitionSuccess::PlayCard
         }
     }
 
@@ -350,9 +751,14 @@ impl Game {
         self.player_c.hand = hands.pop().unwrap();
         self.player_d.hand = hands.pop().unwrap();
 
-        self.player_a.hand.sort();
-        self.player_b.hand.sort();
-        self.player_c.hand.sort();
-        self.player_d.hand.sort();
+        cards::sort_hand(&mut self.player_a.hand, self.player_a.hand_sort);
+        cards::sort_hand(&mut self.player_b.hand, self.player_b.hand_sort);
+        cards::sort_hand(&mut self.player_c.hand, self.player_c.hand_sort);
+        cards::sort_hand(&mut self.player_d.hand, self.player_d.hand_sort);
+
+        self.player_a.hand_revealed = false;
+        self.player_b.hand_revealed = false;
+        self.player_c.hand_revealed = false;
+        self.player_d.hand_revealed = false;
     }
 }
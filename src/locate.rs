@@ -0,0 +1,44 @@
+use cards::{Card, Suit, Rank};
+use super::Game;
+
+/// Where a card was found by [`Game::locate_card`](struct.Game.html#method.locate_card). Rounds and tricks count from
+/// 0, like [`ReviewFlag`](struct.ReviewFlag.html).
+#[derive(Debug, PartialEq)]
+pub enum CardLocation {
+    /// In the hand of the given seat.
+    Hand(usize),
+    /// Played by the given seat into the trick in progress.
+    Pot(usize),
+    /// Played in a completed trick.
+    PlayedInTrick { round: usize, trick: usize },
+    /// Not yet dealt. Every card is dealt at `Start` and a misdeal re-deals at once, so this only happens before the
+    /// game starts.
+    Deck,
+}
+
+/// See [`Game::locate_card`](struct.Game.html#method.locate_card).
+pub fn locate_card(game: &Game, card: &Card) -> Option<CardLocation> {
+    if card.suit == Suit::Blank || card.rank == Rank::Blank {
+        return None;
+    }
+
+    let hands = [&game.player_a.hand, &game.player_b.hand, &game.player_c.hand, &game.player_d.hand];
+    if let Some(seat) = hands.iter().position(|hand| hand.contains(card)) {
+        return Some(CardLocation::Hand(seat));
+    }
+
+    // The pot after the last completed trick is the one in progress, if any.
+    for (i, pot) in game.hands_played.iter().enumerate().rev() {
+        if let Some(seat) = pot.iter().position(|x| x == card) {
+            if i >= game.trick_leaders.len() {
+                return Some(CardLocation::Pot(seat));
+            }
+            return Some(CardLocation::PlayedInTrick { round: i / 13, trick: i % 13 });
+        }
+    }
+
+    if game.deck.contains(card) {
+        return Some(CardLocation::Deck);
+    }
+    None
+}
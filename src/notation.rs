@@ -0,0 +1,213 @@
+use uuid::Uuid;
+use cards::{Card, Suit, Rank};
+use result::NotationError;
+use game_state::State;
+use super::{Game, GameTransition};
+
+const SEATS: [&str; 4] = ["A", "B", "C", "D"];
+
+fn card_to_notation(card: &Card) -> String {
+    let rank = match card.rank {
+        Rank::Blank => "-",
+        Rank::Two => "2",
+        Rank::Three => "3",
+        Rank::Four => "4",
+        Rank::Five => "5",
+        Rank::Six => "6",
+        Rank::Seven => "7",
+        Rank::Eight => "8",
+        Rank::Nine => "9",
+        Rank::Ten => "10",
+        Rank::Jack => "J",
+        Rank::Queen => "Q",
+        Rank::King => "K",
+        Rank::Ace => "A",
+    };
+    let suit = match card.suit {
+        Suit::Blank => "-",
+        Suit::Club => "C",
+        Suit::Diamond => "D",
+        Suit::Heart => "H",
+        Suit::Spade => "S",
+    };
+    format!("{}{}", rank, suit)
+}
+
+fn card_from_notation(notation: &str) -> Option<Card> {
+    if notation.len() < 2 || !notation.is_char_boundary(notation.len() - 1) {
+        return None;
+    }
+    let (rank, suit) = notation.split_at(notation.len() - 1);
+    let rank = match rank {
+        "2" => Rank::Two,
+        "3" => Rank::Three,
+        "4" => Rank::Four,
+        "5" => Rank::Five,
+        "6" => Rank::Six,
+        "7" => Rank::Seven,
+        "8" => Rank::Eight,
+        "9" => Rank::Nine,
+        "10" => Rank::Ten,
+        "J" => Rank::Jack,
+        "Q" => Rank::Queen,
+        "K" => Rank::King,
+        "A" => Rank::Ace,
+        _ => return None,
+    };
+    let suit = match suit {
+        "C" => Suit::Club,
+        "D" => Suit::Diamond,
+        "H" => Suit::Heart,
+        "S" => Suit::Spade,
+        _ => return None,
+    };
+    Some(Card { suit, rank })
+}
+
+fn seat_from_notation(notation: &str) -> Option<usize> {
+    SEATS.iter().position(|x| *x == notation)
+}
+
+/// Writes every completed round of `game`, see [`Game::to_notation`](struct.Game.html#method.to_notation).
+pub fn to_notation(game: &Game) -> String {
+    let mut notation = format!("MaxPoints {}\n", game.scoring.config.max_points);
+
+    for (round, record) in game.scoring.rounds.iter().enumerate() {
+        let bets: Vec<String> = game.scoring.bets_placed[round].iter()
+            .zip(game.scoring.blind_nils[round].iter())
+            .map(|(bet, &blind)| if blind { "BN".to_string() } else { bet.to_string() })
+            .collect();
+        notation.push_str(&format!("Round {}\n", round + 1));
+        notation.push_str(&format!("Bids A {} B {} C {} D {}\n", bets[0], bets[1], bets[2], bets[3]));
+
+        for trick in 0..13 {
+            let cards = &game.hands_played[round * 13 + trick];
+            let leader = game.trick_leaders[round * 13 + trick];
+            let plays: Vec<String> = (0..4)
+                .map(|i| (leader + i) % 4)
+                .map(|seat| format!("{}:{}", SEATS[seat], card_to_notation(&cards[seat])))
+                .collect();
+            notation.push_str(&format!("Trick {} {}\n", trick + 1, plays.join(" ")));
+        }
+
+        notation.push_str(&format!("Score A {} B {}\n", record.cumulative_points[0], record.cumulative_points[1]));
+    }
+
+    notation
+}
+
+/// Splits `line` into words, checking that it starts with `keyword` and has `len` words in total.
+fn words<'a>(line: Option<&(usize, &'a str)>, keyword: &str, len: usize) -> Result<(usize, Vec<&'a str>), NotationError> {
+    match line {
+        Some(&(number, line)) => {
+            let words: Vec<&str> = line.split_whitespace().collect();
+            if words.len() != len || words[0] != keyword {
+                return Err(NotationError::InvalidLine(number));
+            }
+            Ok((number, words))
+        },
+        None => Err(NotationError::UnexpectedEnd),
+    }
+}
+
+fn number(number: usize, word: &str) -> Result<i32, NotationError> {
+    word.parse().map_err(|_| NotationError::InvalidLine(number))
+}
+
+/// Replays a game written by `to_notation`, see [`Game::from_notation`](struct.Game.html#method.from_notation).
+pub fn from_notation(notation: &str) -> Result<Game, NotationError> {
+    let lines: Vec<(usize, &str)> = notation.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|&(_, line)| !line.is_empty())
+        .collect();
+    let mut lines = lines.iter();
+
+    let (line, max_points) = words(lines.next(), "MaxPoints", 2)?;
+    let max_points = number(line, max_points[1])?;
+    let mut game = Game::new(Uuid::new_v4(), [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()], max_points).unwrap();
+    game.play(GameTransition::Start).unwrap();
+
+    let mut round = 0;
+    while let Some(next) = lines.next() {
+        round += 1;
+        let (line, header) = words(Some(next), "Round", 2)?;
+        if number(line, header[1])? != round {
+            return Err(NotationError::InvalidLine(line));
+        }
+
+        let (bids_line, bids) = words(lines.next(), "Bids", 9)?;
+        let mut bets = vec![];
+        for seat in 0..4 {
+            if seat_from_notation(bids[1 + seat * 2]) != Some(seat) {
+                return Err(NotationError::InvalidLine(bids_line));
+            }
+            bets.push(match bids[2 + seat * 2] {
+                "BN" => GameTransition::BlindNil,
+                bet => GameTransition::Bet(number(bids_line, bet)?),
+            });
+        }
+
+        let mut tricks = vec![];
+        let mut hands: [Vec<Card>; 4] = [vec![], vec![], vec![], vec![]];
+        for trick in 1..14 {
+            let (line, trick_words) = words(lines.next(), "Trick", 6)?;
+            if number(line, trick_words[1])? != trick {
+                return Err(NotationError::InvalidLine(line));
+            }
+            let mut plays = vec![];
+            for play in &trick_words[2..] {
+                let mut parts = play.splitn(2, ':');
+                let seat = parts.next().and_then(seat_from_notation).ok_or(NotationError::InvalidLine(line))?;
+                let card = parts.next().unwrap_or("");
+                let card = card_from_notation(card).ok_or_else(|| NotationError::InvalidCard(card.to_string()))?;
+                if hands.iter().any(|hand| hand.contains(&card)) {
+                    return Err(NotationError::InvalidLine(line));
+                }
+                hands[seat].push(card.clone());
+                plays.push((seat, card));
+            }
+            tricks.push((line, plays));
+        }
+        if hands.iter().any(|hand| hand.len() != 13) {
+            return Err(NotationError::InvalidLine(tricks[12].0));
+        }
+
+        for hand in hands.iter_mut() {
+            hand.sort();
+        }
+        let [hand_a, hand_b, hand_c, hand_d] = hands;
+        game.player_a.hand = hand_a;
+        game.player_b.hand = hand_b;
+        game.player_c.hand = hand_c;
+        game.player_d.hand = hand_d;
+
+        for bet in bets {
+            game.play(bet).map_err(|e| NotationError::IllegalTransition(bids_line, e))?;
+        }
+        for (line, plays) in tricks {
+            for (seat, card) in plays {
+                if game.current_player_index != seat {
+                    return Err(NotationError::OutOfTurn(line));
+                }
+                game.play(GameTransition::Card(card)).map_err(|e| NotationError::IllegalTransition(line, e))?;
+            }
+        }
+
+        let (line, score) = words(lines.next(), "Score", 5)?;
+        if score[1] != "A" || score[3] != "B" {
+            return Err(NotationError::InvalidLine(line));
+        }
+        if number(line, score[2])? != game.scoring.team_a.cumulative_points || number(line, score[4])? != game.scoring.team_b.cumulative_points {
+            return Err(NotationError::ScoreMismatch(line));
+        }
+
+        if game.state == State::Completed {
+            if let Some(&(line, _)) = lines.next() {
+                return Err(NotationError::InvalidLine(line));
+            }
+        }
+    }
+
+    Ok(game)
+}
@@ -8,15 +8,19 @@ pub enum TransitionSuccess {
     Trick,
     PlayCard,
     GameOver,
-    Start
+    Start,
+    NextRound,
+    Misdeal
 }
 
 #[derive(Debug, PartialEq)]
 pub enum GetError {
     InvalidUuid,
+    InvalidSeat,
     GameNotStarted,
     GameCompleted,
     GameNotCompleted,
+    RoundOver,
     Unknown
 }
 
@@ -25,18 +29,123 @@ impl fmt::Display for GetError {
         match &self {
             GetError::InvalidUuid => {
                 write!(f, "Error: Attempted to retrieve by an invalid Uuid")},
+            GetError::InvalidSeat => {
+                write!(f, "Error: Attempted to retrieve by a seat other than 0 to 3.")},
             GetError::GameNotStarted => {
                 write!(f, "Error: Game not started yet.")},
             GetError::GameCompleted => {
                 write!(f, "Error: Game is completed.")},
             GetError::GameNotCompleted => {
                 write!(f, "Error: Game is still ongoing.")},
+            GetError::RoundOver => {
+                write!(f, "Error: Round is over, waiting for the next round.")},
             GetError::Unknown => {
                 write!(f, "Error: Unknown get error occurred.")},
         }
     }
 }
 
+#[derive(Debug, PartialEq)]
+pub enum NameError {
+    Empty,
+    TooLong
+}
+
+impl fmt::Display for NameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            NameError::Empty => {
+                write!(f, "Error: Attempted to set an empty name.")},
+            NameError::TooLong => {
+                write!(f, "Error: Attempted to set a name longer than 32 characters.")},
+        }
+    }
+}
+
+impl Error for NameError {
+    fn description(&self) -> &str {
+        "A name error occured."
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum NotationError {
+    UnexpectedEnd,
+    InvalidLine(usize),
+    InvalidCard(String),
+    OutOfTurn(usize),
+    IllegalTransition(usize, TransitionError),
+    ScoreMismatch(usize)
+}
+
+impl fmt::Display for NotationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            NotationError::UnexpectedEnd => {
+                write!(f, "Error: Notation ended in the middle of a round.")},
+            NotationError::InvalidLine(line) => {
+                write!(f, "Error: Line {} of the notation is malformed.", line)},
+            NotationError::InvalidCard(card) => {
+                write!(f, "Error: {} is not a valid card.", card)},
+            NotationError::OutOfTurn(line) => {
+                write!(f, "Error: Line {} of the notation plays a card out of turn.", line)},
+            NotationError::IllegalTransition(line, error) => {
+                write!(f, "Error: Line {} of the notation could not be replayed. {}", line, error)},
+            NotationError::ScoreMismatch(line) => {
+                write!(f, "Error: Line {} of the notation does not match the replayed score.", line)},
+        }
+    }
+}
+
+impl Error for NotationError {
+    fn description(&self) -> &str {
+        "A notation error occured."
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseCardError {
+    InvalidEncoding(u8),
+    Blank
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            ParseCardError::InvalidEncoding(value) => {
+                write!(f, "Error: {} is not a valid card encoding.", value)},
+            ParseCardError::Blank => {
+                write!(f, "Error: Attempted to encode a blank card.")},
+        }
+    }
+}
+
+impl Error for ParseCardError {
+    fn description(&self) -> &str {
+        "A card parse error occured."
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BuildError {
+    DuplicatePlayerIds
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            BuildError::DuplicatePlayerIds => {
+                write!(f, "Error: Attempted to create a game with the same player id in more than one seat.")},
+        }
+    }
+}
+
+impl Error for BuildError {
+    fn description(&self) -> &str {
+        "A build error occured."
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum TransitionError {
     AlreadyStarted,
@@ -45,7 +154,12 @@ pub enum TransitionError {
     BetInTrickStage,
     CompletedGame,
     CardNotInHand,
-    CardIncorrectSuit
+    CardIncorrectSuit,
+    RoundNotOver,
+    AwaitingNextRound,
+    InvalidMisdeal,
+    InvalidBet,
+    HandRevealed
 }
 
 impl fmt::Display for TransitionError {
@@ -65,6 +179,16 @@ impl fmt::Display for TransitionError {
                 write!(f, "Error: Attempted to play a card not in hand.")},
             TransitionError::CardIncorrectSuit => {
                 write!(f, "Error: Attempted to play a of the wrong suit.")},
+            TransitionError::RoundNotOver => {
+                write!(f, "Error: Attempted to start the next round before the current round is over.")},
+            TransitionError::AwaitingNextRound => {
+                write!(f, "Error: Attempted to play while waiting for the next round to be dealt.")},
+            TransitionError::InvalidMisdeal => {
+                write!(f, "Error: Attempted to declare a misdeal the house rules do not allow.")},
+            TransitionError::InvalidBet => {
+                write!(f, "Error: Attempted to place a bet outside of 0 to 13 tricks.")},
+            TransitionError::HandRevealed => {
+                write!(f, "Error: Attempted to bid blind nil after seeing the hand.")},
         }
     }
 }
@@ -73,7 +197,7 @@ impl Error for TransitionError {
     fn description(&self) -> &str {
         "A transition error occured."
     }
-    fn cause(&self) -> Option<&Error> {
+    fn cause(&self) -> Option<&dyn Error> {
         Some(self)
     }
 }
\ No newline at end of file
@@ -0,0 +1,42 @@
+use cards::{Card, Suit};
+
+/// Advisory flag raised by [`Game::review_flags`](struct.Game.html#method.review_flags): `seat` played `suit` in
+/// `trick` of `round` after failing to follow `suit` when it was led in `void_trick` of the same round.
+#[derive(Debug, PartialEq)]
+pub struct ReviewFlag {
+    pub seat: usize,
+    pub round: usize,
+    pub trick: usize,
+    pub suit: Suit,
+    pub void_trick: usize,
+}
+
+/// Infers each seat's voids from the completed tricks of every round, and flags any later play of a suit the seat
+/// had already shown void in. `hands_played` is indexed by seat, and `trick_leaders` holds the seat that led each trick.
+pub fn review_flags(hands_played: &[[Card; 4]], trick_leaders: &[usize]) -> Vec<ReviewFlag> {
+    let mut flags = vec![];
+    let mut voids: [[Option<usize>; 5]; 4] = [[None; 5]; 4];
+
+    for (i, (cards, &leader)) in hands_played.iter().zip(trick_leaders).enumerate() {
+        let round = i / 13;
+        let trick = i % 13;
+        if trick == 0 {
+            voids = [[None; 5]; 4];
+        }
+
+        for (seat, card) in cards.iter().enumerate() {
+            if let Some(void_trick) = voids[seat][card.suit as usize] {
+                flags.push(ReviewFlag { seat, round, trick, suit: card.suit, void_trick });
+            }
+        }
+
+        let leading_suit = cards[leader].suit;
+        for (seat, card) in cards.iter().enumerate() {
+            if card.suit != leading_suit {
+                voids[seat][leading_suit as usize].get_or_insert(trick);
+            }
+        }
+    }
+
+    flags
+}
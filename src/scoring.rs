@@ -1,11 +1,17 @@
 use cards::{Card, get_trick_winner};
+use game_state::{MisdealRule, Team, CompletionReason};
+
+/// Bags a team can collect before losing 100 points.
+pub const BAG_PENALTY_THRESHOLD: i32 = 10;
 
 #[derive(Debug)]
 pub struct GameConfig {
-    max_points: i32
+    pub max_points: i32,
+    pub manual_round_advance: bool,
+    pub misdeal_rule: MisdealRule
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TeamState {
     pub current_round_tricks_won: [i32 ; 13],
     pub bags: i32,
@@ -21,40 +27,99 @@ impl TeamState {
         }
     }
 
-    fn calculate_round_totals(&mut self, first_bet: i32, first_nil: bool, second_bet:i32, second_nil: bool) {
+    /// Returns a copy of this team's state as if it finishes the round taking exactly `bet` tricks, or the tricks it
+    /// has already won if that is more.
+    fn projected(&self, bet: i32) -> TeamState {
+        let team_tricks : i32 = self.current_round_tricks_won.iter().sum();
+        let mut projected = self.clone();
+        projected.current_round_tricks_won = [0; 13];
+        projected.current_round_tricks_won[0] = team_tricks.max(bet);
+        projected
+    }
+
+    /// Adds the round's points and bags, returning the bags taken this round.
+    fn calculate_round_totals(&mut self, first_bet: i32, first_nil: bool, first_blind: bool, second_bet:i32, second_nil: bool, second_blind: bool) -> i32 {
         let team_tricks : i32 = self.current_round_tricks_won.iter().sum();
 
         let team_bets = first_bet + second_bet;
+        let mut round_bags = 0;
         
         if team_tricks >= team_bets {
-            let round_bags = team_tricks - team_bets;
+            round_bags = team_tricks - team_bets;
             self.bags += round_bags;
-            self.cumulative_points += round_bags + (team_bets * 10) as i32;
+            self.cumulative_points += round_bags + team_bets * 10;
         }
 
-        if self.bags >= 10 {
-            self.bags -= 10;
+        while self.bags >= BAG_PENALTY_THRESHOLD {
+            self.bags -= BAG_PENALTY_THRESHOLD;
             self.cumulative_points -= 100;
         }
         
         if first_bet == 0 {
+            let bonus = if first_blind { 200 } else { 100 };
             if !first_nil {
-                self.cumulative_points += 100;
+                self.cumulative_points += bonus;
             } else {
-                self.cumulative_points -= 100;
+                self.cumulative_points -= bonus;
             }
         }
         if second_bet == 0 {
+            let bonus = if second_blind { 200 } else { 100 };
             if !second_nil {
-                self.cumulative_points += 100;
+                self.cumulative_points += bonus;
             } else {
-                self.cumulative_points -= 100;
+                self.cumulative_points -= bonus;
             }
         }
 
+        round_bags
     }
 }
 
+/// Each team's score if both make exactly their bids for the rest of the round, see
+/// [`Game::projected_outcome`](struct.Game.html#method.projected_outcome).
+#[derive(Debug, PartialEq)]
+pub struct ProjectedOutcome {
+    pub team_a_score: i32,
+    pub team_b_score: i32,
+    pub game_over: bool,
+}
+
+/// Result of a completed game, see [`Game::get_winner`](struct.Game.html#method.get_winner). `winner` is `None` when the
+/// teams finish on the same score, and `scores` holds the final points of teams `A` and `B`.
+#[derive(Debug, PartialEq)]
+pub struct GameOutcome {
+    pub winner: Option<Team>,
+    pub scores: [i32; 2],
+    pub reason: CompletionReason,
+}
+
+/// Cumulative points of teams `A` and `B` after a round, the bags each took during it (before any penalty), and the
+/// tricks each seat took, see [`Game::round_history`](struct.Game.html#method.round_history).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoundRecord {
+    pub cumulative_points: [i32; 2],
+    pub bags_taken: [i32; 2],
+    pub tricks_taken: [i32; 4],
+}
+
+/// One player's bid in a completed round, see [`Game::bid_history`](struct.Game.html#method.bid_history). A bid is
+/// `made` when the player took at least `bid` tricks, or none at all for a nil bid, whatever their partner took.
+#[derive(Debug, PartialEq)]
+pub struct BidRecord {
+    pub round: usize,
+    pub bid: i32,
+    pub taken: i32,
+    pub made: bool,
+}
+
+fn score_round(bets: [i32; 4], blind_nils: [bool; 4], nil_check: [bool; 4], team_a: &mut TeamState, team_b: &mut TeamState) -> [i32; 2] {
+    [
+        team_a.calculate_round_totals(bets[0], nil_check[0], blind_nils[0], bets[2], nil_check[2], blind_nils[2]),
+        team_b.calculate_round_totals(bets[1], nil_check[1], blind_nils[1], bets[3], nil_check[3], blind_nils[3]),
+    ]
+}
+
 #[derive(Debug)]
 pub struct Scoring {
     pub config: GameConfig,
@@ -62,10 +127,13 @@ pub struct Scoring {
     pub team_b: TeamState,
     pub in_betting_stage: bool,
     pub bets_placed: Vec<[i32; 4]>,
+    pub blind_nils: Vec<[bool; 4]>,
     pub is_over: bool,
     pub round: usize,
     pub trick: usize,
-    pub nil_check: [bool; 4]
+    pub nil_check: [bool; 4],
+    pub tricks_won: [i32; 4],
+    pub rounds: Vec<RoundRecord>
 }
 
 impl Scoring {
@@ -75,17 +143,26 @@ impl Scoring {
             team_b: TeamState::new(),
             in_betting_stage: true,
             bets_placed: vec![[0;4]],
+            blind_nils: vec![[false; 4]],
             is_over: false,
             round: 0,
             trick: 0,
-            config: GameConfig {max_points: max_points},
-            nil_check: [false, false, false, false]
+            config: GameConfig {max_points, manual_round_advance: false, misdeal_rule: MisdealRule::None},
+            nil_check: [false, false, false, false],
+            tricks_won: [0; 4],
+            rounds: vec![]
 
         }
     }
     
-    pub fn add_bet(&mut self, current_player_index: usize, bet: i32) {
+    pub fn add_bet(&mut self, current_player_index: usize, bet: i32, blind: bool) {
         self.bets_placed.last_mut().unwrap()[current_player_index] = bet;
+        self.blind_nils.last_mut().unwrap()[current_player_index] = blind;
+    }
+
+    pub fn clear_bets(&mut self) {
+        *self.bets_placed.last_mut().unwrap() = [0;4];
+        *self.blind_nils.last_mut().unwrap() = [false; 4];
     }
 
     pub fn bet(&mut self) {
@@ -93,27 +170,33 @@ impl Scoring {
         self.in_betting_stage = false;
         
         self.bets_placed.push([0;4]);
+        self.blind_nils.push([false; 4]);
     }
 
     pub fn trick(&mut self, starting_player_index: usize, cards: &[Card; 4]) -> usize {
-        let winner = get_trick_winner(starting_player_index, &cards);
+        let winner = get_trick_winner(starting_player_index, cards);
         self.nil_check[winner] = true;
+        self.tricks_won[winner] += 1;
 
-        if winner % 2 == 0 {
-            self.team_a.current_round_tricks_won[self.trick] += 1;
-        } else {
-            self.team_b.current_round_tricks_won[self.trick] += 1;
+        match Team::of_seat(winner) {
+            Team::A => self.team_a.current_round_tricks_won[self.trick] += 1,
+            Team::B => self.team_b.current_round_tricks_won[self.trick] += 1,
         }
 
         if self.trick == 12 {
-            self.team_a.calculate_round_totals(self.bets_placed[self.round][0], self.nil_check[0], self.bets_placed[self.round][2], self.nil_check[1]);
-            self.team_b.calculate_round_totals(self.bets_placed[self.round][1], self.nil_check[1], self.bets_placed[self.round][3], self.nil_check[3]);
+            let bags_taken = score_round(self.bets_placed[self.round], self.blind_nils[self.round], self.nil_check, &mut self.team_a, &mut self.team_b);
+            self.rounds.push(RoundRecord {
+                cumulative_points: [self.team_a.cumulative_points, self.team_b.cumulative_points],
+                bags_taken,
+                tricks_taken: self.tricks_won,
+            });
             self.nil_check = [false; 4];
+            self.tricks_won = [0; 4];
             self.in_betting_stage = true;
             self.team_a.current_round_tricks_won = [0; 13];
             self.team_b.current_round_tricks_won = [0; 13];
 
-            if self.team_a.cumulative_points >= self.config.max_points {
+            if self.reaches_max_points(&self.team_a, &self.team_b) {
                 self.is_over = true;
             }
             self.round += 1;
@@ -121,6 +204,45 @@ impl Scoring {
             self.trick += 1;
         }
 
-        return winner;
+        winner
+    }
+
+    pub fn projected_outcome(&self) -> ProjectedOutcome {
+        let bets = self.bets_placed[self.round];
+        let mut team_a = self.team_a.projected(bets[0] + bets[2]);
+        let mut team_b = self.team_b.projected(bets[1] + bets[3]);
+        score_round(bets, self.blind_nils[self.round], self.nil_check, &mut team_a, &mut team_b);
+
+        ProjectedOutcome {
+            team_a_score: team_a.cumulative_points,
+            team_b_score: team_b.cumulative_points,
+            game_over: self.reaches_max_points(&team_a, &team_b),
+        }
+    }
+
+    /// Returns `seat`'s bid in every completed round.
+    pub fn bid_history(&self, seat: usize) -> Vec<BidRecord> {
+        self.rounds.iter().enumerate().map(|(round, record)| {
+            let bid = self.bets_placed[round][seat];
+            let taken = record.tricks_taken[seat];
+            let made = if bid == 0 { taken == 0 } else { taken >= bid };
+            BidRecord { round, bid, taken, made }
+        }).collect()
+    }
+
+    pub fn outcome(&self, reason: CompletionReason) -> GameOutcome {
+        let scores = [self.team_a.cumulative_points, self.team_b.cumulative_points];
+        let winner = if scores[0] > scores[1] {
+            Some(Team::A)
+        } else if scores[1] > scores[0] {
+            Some(Team::B)
+        } else {
+            None
+        };
+        GameOutcome { winner, scores, reason }
+    }
+
+    fn reaches_max_points(&self, team_a: &TeamState, team_b: &TeamState) -> bool {
+        team_a.cumulative_points >= self.config.max_points || team_b.cumulative_points >= self.config.max_points
     }
 }
@@ -0,0 +1,55 @@
+use super::super::cards::{Card, Suit, Rank};
+use super::super::{Game, GameTransition, CardLocation};
+use super::super::game_state::State;
+use super::spades_game_api_unit::{new_game, play_valid_card};
+
+fn started_game() -> Game {
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+    for _ in 0..4 {
+        g.play(GameTransition::Bet(3)).unwrap();
+    }
+    g
+}
+
+#[test]
+fn locate_card_in_deck() {
+    let g = new_game();
+    let card = Card { suit: Suit::Spade, rank: Rank::Ace };
+    assert_eq!(g.locate_card(&card), Some(CardLocation::Deck));
+}
+
+#[test]
+fn locate_card_in_hand() {
+    let g = started_game();
+    let card = g.player_c.hand[5].clone();
+    assert_eq!(g.locate_card(&card), Some(CardLocation::Hand(2)));
+}
+
+#[test]
+fn locate_card_in_pot() {
+    let mut g = started_game();
+    for _ in 0..6 {
+        play_valid_card(&mut g).unwrap();
+    }
+    assert_eq!(g.state, State::Trick(2));
+    let seat = (g.current_player_index + 3) % 4;
+    let card = g.hands_played.last().unwrap()[seat].clone();
+    assert_eq!(g.locate_card(&card), Some(CardLocation::Pot(seat)));
+}
+
+#[test]
+fn locate_card_played_in_trick() {
+    let mut g = started_game();
+    for _ in 0..6 {
+        play_valid_card(&mut g).unwrap();
+    }
+    let card = g.hands_played[0][1].clone();
+    assert_eq!(g.locate_card(&card), Some(CardLocation::PlayedInTrick { round: 0, trick: 0 }));
+}
+
+#[test]
+fn locate_blank_card() {
+    let g = started_game();
+    assert_eq!(g.locate_card(&Card { suit: Suit::Blank, rank: Rank::Blank }), None);
+}
@@ -1,2 +1,6 @@
 mod spades_deck_unit;
-mod spades_game_api_unit;
\ No newline at end of file
+mod spades_game_api_unit;
+mod notation_unit;
+mod transition_table_unit;
+#[cfg(feature = "admin-debug")]
+mod locate_unit;
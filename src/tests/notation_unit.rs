@@ -0,0 +1,100 @@
+use super::super::cards::{Card, Suit, Rank};
+use super::super::result::{NotationError, TransitionError};
+use super::super::{Game, GameTransition};
+use super::super::game_state::State;
+use super::spades_game_api_unit::{new_game, play_valid_card, completed_game};
+
+fn replace_line(notation: &str, line: usize, replacement: &str) -> String {
+    let mut lines: Vec<&str> = notation.lines().collect();
+    lines[line - 1] = replacement;
+    lines.join("\n")
+}
+
+#[test]
+fn notation_round_trip() {
+    let g = completed_game();
+    let notation = g.to_notation();
+    let lines: Vec<&str> = notation.lines().collect();
+
+    assert_eq!(lines[0], "MaxPoints 500");
+    assert_eq!(lines[1], "Round 1");
+    assert_eq!(lines[2], "Bids A 3 B 3 C 3 D 3");
+    assert!(lines[3].starts_with("Trick 1 A:"));
+    assert_eq!(lines.len(), 1 + g.scoring.rounds.len() * 16);
+
+    let replayed = Game::from_notation(&notation).unwrap();
+    assert_eq!(replayed.state, State::Completed);
+    assert_eq!(replayed.to_notation(), notation);
+    assert_eq!(replayed.hands_played, g.hands_played);
+    assert_eq!(replayed.trick_leaders, g.trick_leaders);
+    assert_eq!(replayed.scoring.team_a.cumulative_points, g.scoring.team_a.cumulative_points);
+    assert_eq!(replayed.scoring.team_b.cumulative_points, g.scoring.team_b.cumulative_points);
+    assert_eq!(replayed.scoring.team_a.bags, g.scoring.team_a.bags);
+    assert_eq!(replayed.scoring.team_b.bags, g.scoring.team_b.bags);
+}
+
+#[test]
+fn notation_blind_nil() {
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+    while g.state != State::Completed {
+        match g.state {
+            State::Betting(0) => g.play(GameTransition::BlindNil).unwrap(),
+            State::Betting(_) => g.play(GameTransition::Bet(3)).unwrap(),
+            _ => play_valid_card(&mut g).unwrap(),
+        };
+    }
+    let notation = g.to_notation();
+    assert_eq!(notation.lines().nth(2).unwrap(), "Bids A BN B 3 C 3 D 3");
+
+    let replayed = Game::from_notation(&notation).unwrap();
+    assert_eq!(replayed.to_notation(), notation);
+    assert_eq!(replayed.scoring.team_a.cumulative_points, g.scoring.team_a.cumulative_points);
+    assert_eq!(replayed.scoring.blind_nils, g.scoring.blind_nils);
+}
+
+#[test]
+fn notation_card_strings() {
+    let mut g = completed_game();
+    g.hands_played[0][g.trick_leaders[0]] = Card { suit: Suit::Heart, rank: Rank::Ten };
+    assert!(g.to_notation().lines().nth(3).unwrap().starts_with("Trick 1 A:10H "));
+}
+
+#[test]
+fn notation_parse_errors() {
+    let notation = completed_game().to_notation();
+    let trick = notation.lines().nth(3).unwrap().to_string();
+    let plays: Vec<&str> = trick.split_whitespace().collect();
+
+    assert_eq!(Game::from_notation("").err(), Some(NotationError::UnexpectedEnd));
+    assert_eq!(Game::from_notation("MaxPoints many").err(), Some(NotationError::InvalidLine(1)));
+    assert_eq!(Game::from_notation("MaxPoints 500\nRound 2").err(), Some(NotationError::InvalidLine(2)));
+
+    let truncated: Vec<&str> = notation.lines().take(10).collect();
+    assert_eq!(Game::from_notation(&truncated.join("\n")).err(), Some(NotationError::UnexpectedEnd));
+
+    let bad_card = replace_line(&notation, 4, &trick.replacen(&plays[2][2..], "1X", 1));
+    assert_eq!(Game::from_notation(&bad_card).err(), Some(NotationError::InvalidCard("1X".to_string())));
+
+    let out_of_turn = format!("Trick 1 {} {} {} {}", plays[3], plays[2], plays[4], plays[5]);
+    assert_eq!(Game::from_notation(&replace_line(&notation, 4, &out_of_turn)).err(), Some(NotationError::OutOfTurn(4)));
+
+    let bad_bid = replace_line(&notation, 3, "Bids A 14 B 3 C 3 D 3");
+    assert_eq!(Game::from_notation(&bad_bid).err(), Some(NotationError::IllegalTransition(3, TransitionError::InvalidBet)));
+
+    let bad_score = replace_line(&notation, 17, "Score A 1000 B 0");
+    assert_eq!(Game::from_notation(&bad_score).err(), Some(NotationError::ScoreMismatch(17)));
+
+    let trailing = format!("{}Round 99\n", notation);
+    assert_eq!(Game::from_notation(&trailing).err(), Some(NotationError::InvalidLine(notation.lines().count() + 1)));
+}
+
+#[test]
+fn state_digest_survives_notation() {
+    let g = completed_game();
+    let replayed = Game::from_notation(&g.to_notation()).unwrap();
+
+    assert_eq!(replayed.state_digest(), g.state_digest());
+    assert!(replayed.equivalent(&g));
+    assert!(!replayed.equivalent(&new_game()));
+}
@@ -1,5 +1,8 @@
 use super::super::cards::{Card, Suit, Rank, get_trick_winner, deal_four_players};
+use super::super::result::ParseCardError;
+use super::super::game_state::HandSortOrder;
 use super::super::cards;
+use std::convert::TryFrom;
 #[test]
 fn new_deck() {
     let x = cards::new_deck();
@@ -10,7 +13,7 @@ fn new_deck() {
 fn deal_deck_four_players() {
 
     let mut x = cards::new_deck();
-    let y = x[0].suit.clone();
+    let y = x[0].suit;
 
     println!("{:?}", y as u8);
 
@@ -70,3 +73,112 @@ fn trick_winner_spades() {
     assert_eq!(2, get_trick_winner(2, &trick));
     assert_eq!(2, get_trick_winner(3, &trick));
 }
+
+#[test]
+fn card_u8_round_trip() {
+    let mut deck = cards::new_deck();
+    deck.sort();
+
+    for (i, card) in deck.iter().enumerate() {
+        assert_eq!(card.to_u8(), Ok(i as u8));
+        assert_eq!(Card::from_u8(card.to_u8().unwrap()), Ok(card.clone()));
+        assert_eq!(u8::try_from(card.clone()), card.to_u8());
+    }
+    assert_eq!(Card::from_u8(0), Ok(Card { suit: Suit::Club, rank: Rank::Two }));
+    assert_eq!(Card::from_u8(51), Ok(Card { suit: Suit::Spade, rank: Rank::Ace }));
+}
+
+#[test]
+fn card_from_u8_out_of_range() {
+    for value in 52..=255 {
+        assert_eq!(Card::from_u8(value), Err(ParseCardError::InvalidEncoding(value)));
+    }
+}
+
+#[test]
+fn card_to_u8_blank() {
+    assert_eq!(Card { suit: Suit::Blank, rank: Rank::Blank }.to_u8(), Err(ParseCardError::Blank));
+    assert_eq!(Card { suit: Suit::Spade, rank: Rank::Blank }.to_u8(), Err(ParseCardError::Blank));
+    assert_eq!(u8::try_from(Card { suit: Suit::Blank, rank: Rank::Ace }), Err(ParseCardError::Blank));
+}
+
+#[test]
+fn card_names() {
+    let mut deck = cards::new_deck();
+    deck.sort();
+    let suits = ["clubs", "diamonds", "hearts", "spades"];
+    let ranks = ["two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "jack", "queen", "king", "ace"];
+
+    for (i, card) in deck.iter().enumerate() {
+        assert_eq!(card.suit.name(), suits[i / 13]);
+        assert_eq!(card.rank.name(), ranks[i % 13]);
+        assert_eq!(card.long_name(), format!("{} of {}", ranks[i % 13], suits[i / 13]));
+    }
+    assert_eq!(Card { suit: Suit::Spade, rank: Rank::Queen }.long_name(), "queen of spades");
+    assert_eq!(Card { suit: Suit::Diamond, rank: Rank::Ten }.long_name(), "ten of diamonds");
+}
+
+#[test]
+fn card_emoji() {
+    let mut deck = cards::new_deck();
+    deck.sort();
+    let suits = ["\u{1F0D1}\u{1F0D2}\u{1F0D3}\u{1F0D4}\u{1F0D5}\u{1F0D6}\u{1F0D7}\u{1F0D8}\u{1F0D9}\u{1F0DA}\u{1F0DB}\u{1F0DD}\u{1F0DE}",
+                 "\u{1F0C1}\u{1F0C2}\u{1F0C3}\u{1F0C4}\u{1F0C5}\u{1F0C6}\u{1F0C7}\u{1F0C8}\u{1F0C9}\u{1F0CA}\u{1F0CB}\u{1F0CD}\u{1F0CE}",
+                 "\u{1F0B1}\u{1F0B2}\u{1F0B3}\u{1F0B4}\u{1F0B5}\u{1F0B6}\u{1F0B7}\u{1F0B8}\u{1F0B9}\u{1F0BA}\u{1F0BB}\u{1F0BD}\u{1F0BE}",
+                 "\u{1F0A1}\u{1F0A2}\u{1F0A3}\u{1F0A4}\u{1F0A5}\u{1F0A6}\u{1F0A7}\u{1F0A8}\u{1F0A9}\u{1F0AA}\u{1F0AB}\u{1F0AD}\u{1F0AE}"];
+
+    for (suit, cards) in deck.chunks(13).enumerate() {
+        // Each suit runs ace first in the Unicode block, so the ace's character leads the expected string.
+        let expected: Vec<char> = suits[suit].chars().collect();
+        assert_eq!(cards[12].emoji(), expected[0]);
+        for (i, card) in cards[..12].iter().enumerate() {
+            assert_eq!(card.emoji(), expected[i + 1]);
+        }
+    }
+    assert_eq!(Card { suit: Suit::Spade, rank: Rank::Queen }.emoji(), '\u{1F0AD}');
+}
+
+#[test]
+fn blank_card_names() {
+    let blank = Card { suit: Suit::Blank, rank: Rank::Blank };
+    assert_eq!(blank.suit.name(), "\u{2014}");
+    assert_eq!(blank.rank.name(), "\u{2014}");
+    assert_eq!(blank.long_name(), "\u{2014}");
+    assert_eq!(blank.emoji(), '\u{2014}');
+    assert_eq!(Card { suit: Suit::Heart, rank: Rank::Blank }.long_name(), "\u{2014}");
+    assert_eq!(Card { suit: Suit::Blank, rank: Rank::Ace }.emoji(), '\u{2014}');
+}
+
+#[test]
+fn sort_hand_orders() {
+    let mut hand = vec![
+        Card { suit: Suit::Spade, rank: Rank::Two },
+        Card { suit: Suit::Club, rank: Rank::Ace },
+        Card { suit: Suit::Heart, rank: Rank::Two },
+        Card { suit: Suit::Club, rank: Rank::Ten },
+    ];
+
+    cards::sort_hand(&mut hand, HandSortOrder::BySuit);
+    assert_eq!(hand, vec![
+        Card { suit: Suit::Club, rank: Rank::Ten },
+        Card { suit: Suit::Club, rank: Rank::Ace },
+        Card { suit: Suit::Heart, rank: Rank::Two },
+        Card { suit: Suit::Spade, rank: Rank::Two },
+    ]);
+
+    cards::sort_hand(&mut hand, HandSortOrder::BySuitDescending);
+    assert_eq!(hand, vec![
+        Card { suit: Suit::Spade, rank: Rank::Two },
+        Card { suit: Suit::Heart, rank: Rank::Two },
+        Card { suit: Suit::Club, rank: Rank::Ace },
+        Card { suit: Suit::Club, rank: Rank::Ten },
+    ]);
+
+    cards::sort_hand(&mut hand, HandSortOrder::ByRank);
+    assert_eq!(hand, vec![
+        Card { suit: Suit::Heart, rank: Rank::Two },
+        Card { suit: Suit::Spade, rank: Rank::Two },
+        Card { suit: Suit::Club, rank: Rank::Ten },
+        Card { suit: Suit::Club, rank: Rank::Ace },
+    ]);
+}
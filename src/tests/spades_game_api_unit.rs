@@ -1,9 +1,12 @@
 extern crate uuid;
+extern crate rand;
 
 use super::super::cards::{Card, Suit, Rank};
-use super::super::result::{TransitionSuccess, TransitionError};
-use super::super::{Game, GameTransition};
-use super::super::game_state::State;
+use super::super::cards;
+use super::super::result::{TransitionSuccess, TransitionError, GetError, BuildError, NameError};
+use super::super::{Game, GameTransition, ProjectedOutcome, GameOutcome, BidRecord, RoundRecord, ReviewFlag};
+use super::super::game_state::{State, MisdealRule, Team, CompletionReason, HandSortOrder};
+use self::rand::{thread_rng, Rng};
 
 #[allow(unused)]
 #[test]
@@ -13,7 +16,7 @@ pub fn api_main_unit() {
          uuid::Uuid::new_v4(), 
          uuid::Uuid::new_v4(), 
          uuid::Uuid::new_v4()], 
-         500);
+         500).unwrap();
 
 
     assert_eq!(g.play(GameTransition::Card(Card { suit: Suit::Heart, rank: Rank::Five })), Err(TransitionError::NotStarted));
@@ -172,4 +175,821 @@ pub fn api_main_unit() {
     //     trick_test_closure(t_n, &pots[t_n], trick_winners[t_n]);
     // }
     
-}
\ No newline at end of file
+}
+#[test]
+fn new_game_duplicate_player_ids() {
+    let duplicate = uuid::Uuid::new_v4();
+    assert_eq!(Game::new(uuid::Uuid::new_v4(), [duplicate; 4], 500).err(), Some(BuildError::DuplicatePlayerIds));
+
+    let g = Game::new(uuid::Uuid::new_v4(), 
+        [uuid::Uuid::new_v4(), 
+         duplicate, 
+         uuid::Uuid::new_v4(), 
+         duplicate], 
+         500);
+    assert_eq!(g.err(), Some(BuildError::DuplicatePlayerIds));
+}
+
+#[test]
+fn new_game_distinct_player_ids() {
+    let player_ids = [uuid::Uuid::new_v4(), 
+         uuid::Uuid::new_v4(), 
+         uuid::Uuid::new_v4(), 
+         uuid::Uuid::new_v4()];
+    let mut g = Game::new(uuid::Uuid::new_v4(), player_ids, 500).unwrap();
+    g.play(GameTransition::Start).unwrap();
+
+    assert_eq!(g.get_hand_by_player_id(player_ids[0]), Ok(&g.player_a.hand));
+    assert_eq!(g.get_hand_by_player_id(player_ids[1]), Ok(&g.player_b.hand));
+    assert_eq!(g.get_hand_by_player_id(player_ids[2]), Ok(&g.player_c.hand));
+    assert_eq!(g.get_hand_by_player_id(player_ids[3]), Ok(&g.player_d.hand));
+    assert_eq!(g.get_hand_by_player_id(uuid::Uuid::new_v4()), Err(GetError::InvalidUuid));
+}
+
+#[test]
+fn bet_status_by_seat() {
+    let mut g = Game::new(uuid::Uuid::new_v4(), 
+        [uuid::Uuid::new_v4(), 
+         uuid::Uuid::new_v4(), 
+         uuid::Uuid::new_v4(), 
+         uuid::Uuid::new_v4()], 
+         500).unwrap();
+    assert_eq!(g.bet_status_by_seat(), Err(GetError::GameNotStarted));
+
+    g.play(GameTransition::Start).unwrap();
+    assert_eq!(g.bet_status_by_seat(), Ok([false, false, false, false]));
+
+    g.play(GameTransition::Bet(0)).unwrap();
+    assert_eq!(g.bet_status_by_seat(), Ok([true, false, false, false]));
+
+    g.play(GameTransition::Bet(4)).unwrap();
+    assert_eq!(g.bet_status_by_seat(), Ok([true, true, false, false]));
+
+    g.play(GameTransition::Bet(3)).unwrap();
+    assert_eq!(g.bet_status_by_seat(), Ok([true, true, true, false]));
+
+    assert_eq!(g.play(GameTransition::Bet(3)), Ok(TransitionSuccess::BetComplete));
+    assert_eq!(g.bet_status_by_seat(), Err(GetError::Unknown));
+}
+
+pub fn new_game() -> Game {
+    Game::new(uuid::Uuid::new_v4(), 
+        [uuid::Uuid::new_v4(), 
+         uuid::Uuid::new_v4(), 
+         uuid::Uuid::new_v4(), 
+         uuid::Uuid::new_v4()], 
+         500).unwrap()
+}
+
+pub fn play_valid_card(g: &mut Game) -> Result<TransitionSuccess, TransitionError> {
+    let hand = g.get_current_hand().unwrap().clone();
+    let card = match g.state {
+        State::Trick(0) => hand[0].clone(),
+        _ => hand.iter().find(|x| x.suit == g.leading_suit).unwrap_or(&hand[0]).clone(),
+    };
+    g.play(GameTransition::Card(card))
+}
+
+/// Plays a game to completion, betting 3 for every seat.
+pub fn completed_game() -> Game {
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+    while g.state != State::Completed {
+        if let State::Betting(_) = g.state {
+            g.play(GameTransition::Bet(3)).unwrap();
+        } else {
+            play_valid_card(&mut g).unwrap();
+        }
+    }
+    g
+}
+
+pub fn play_round(g: &mut Game) -> Result<TransitionSuccess, TransitionError> {
+    for _ in 0..4 {
+        g.play(GameTransition::Bet(3)).unwrap();
+    }
+    for _ in 0..51 {
+        play_valid_card(g).unwrap();
+    }
+    play_valid_card(g)
+}
+
+#[test]
+fn automatic_round_advance() {
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+
+    assert_eq!(g.play(GameTransition::NextRound), Err(TransitionError::RoundNotOver));
+    assert_eq!(play_round(&mut g), Ok(TransitionSuccess::Trick));
+    assert_eq!(g.state, State::Betting(0));
+    assert_eq!(g.player_a.hand.len(), 13);
+    assert_eq!(g.play(GameTransition::NextRound), Err(TransitionError::RoundNotOver));
+}
+
+#[test]
+fn manual_round_advance() {
+    let mut g = new_game();
+    g.set_manual_round_advance(true);
+    assert_eq!(g.play(GameTransition::NextRound), Err(TransitionError::NotStarted));
+    g.play(GameTransition::Start).unwrap();
+
+    assert_eq!(play_round(&mut g), Ok(TransitionSuccess::Trick));
+    assert_eq!(g.state, State::RoundOver);
+    assert!(g.player_a.hand.is_empty());
+    assert_eq!(g.get_current_trick_cards(), Ok(&g.hands_played[12]));
+    assert_eq!(g.get_current_player_id(), Err(GetError::RoundOver));
+    assert_eq!(g.get_current_hand(), Err(GetError::RoundOver));
+    assert_eq!(g.get_leading_suit(), Err(GetError::RoundOver));
+    assert_eq!(g.bet_status_by_seat(), Err(GetError::RoundOver));
+    assert_eq!(g.projected_outcome(), Err(GetError::RoundOver));
+    assert_eq!(g.play(GameTransition::Bet(3)), Err(TransitionError::AwaitingNextRound));
+    assert_eq!(g.play(GameTransition::Card(Card { suit: Suit::Heart, rank: Rank::Five })), Err(TransitionError::AwaitingNextRound));
+    assert_eq!(g.play(GameTransition::Start), Err(TransitionError::AlreadyStarted));
+
+    assert_eq!(g.play(GameTransition::NextRound), Ok(TransitionSuccess::NextRound));
+    assert_eq!(g.state, State::Betting(0));
+    assert_eq!(g.player_a.hand.len(), 13);
+    assert_eq!(g.play(GameTransition::NextRound), Err(TransitionError::RoundNotOver));
+    assert_eq!(g.play(GameTransition::Bet(3)), Ok(TransitionSuccess::Bet));
+}
+
+pub fn deal_with_first_hand(g: &mut Game, first_hand: fn(&Card) -> bool) {
+    let mut deck = cards::new_deck();
+    deck.sort();
+    let (mut matching, rest): (Vec<Card>, Vec<Card>) = deck.into_iter().partition(first_hand);
+    matching.extend(rest);
+    let mut cards = matching.into_iter();
+
+    g.player_a.hand = cards.by_ref().take(13).collect();
+    g.player_b.hand = cards.by_ref().take(13).collect();
+    g.player_c.hand = cards.by_ref().take(13).collect();
+    g.player_d.hand = cards.collect();
+}
+
+#[test]
+fn misdeal_not_allowed_by_default() {
+    let mut g = new_game();
+    assert_eq!(g.play(GameTransition::DeclareMisdeal), Err(TransitionError::NotStarted));
+    g.play(GameTransition::Start).unwrap();
+    deal_with_first_hand(&mut g, |x| x.suit == Suit::Club);
+
+    assert_eq!(g.play(GameTransition::DeclareMisdeal), Err(TransitionError::InvalidMisdeal));
+    assert_eq!(g.state, State::Betting(0));
+}
+
+#[test]
+fn misdeal_no_spades() {
+    let mut g = new_game();
+    g.set_misdeal_rule(MisdealRule::NoSpades);
+    g.play(GameTransition::Start).unwrap();
+    deal_with_first_hand(&mut g, |x| x.suit == Suit::Spade);
+    let hand_b = g.player_b.hand.clone();
+
+    assert_eq!(g.play(GameTransition::DeclareMisdeal), Err(TransitionError::InvalidMisdeal));
+    g.play(GameTransition::Bet(3)).unwrap();
+    assert_eq!(g.play(GameTransition::DeclareMisdeal), Ok(TransitionSuccess::Misdeal));
+
+    assert_eq!(g.state, State::Betting(0));
+    assert_eq!(g.current_player_index, 0);
+    assert_eq!(*g.scoring.bets_placed.last().unwrap(), [0; 4]);
+    assert_ne!(g.player_b.hand, hand_b);
+    assert!(g.deck.is_empty());
+    for hand in [&g.player_a.hand, &g.player_b.hand, &g.player_c.hand, &g.player_d.hand].iter() {
+        assert_eq!(hand.len(), 13);
+    }
+}
+
+#[test]
+fn misdeal_no_face_cards() {
+    let mut g = new_game();
+    g.set_misdeal_rule(MisdealRule::NoFaceCards);
+    g.play(GameTransition::Start).unwrap();
+    deal_with_first_hand(&mut g, |x| (x.rank as u8) < (Rank::Jack as u8) && x.suit != Suit::Spade);
+
+    assert_eq!(g.play(GameTransition::DeclareMisdeal), Ok(TransitionSuccess::Misdeal));
+    assert_eq!(g.state, State::Betting(0));
+
+    deal_with_first_hand(&mut g, |x| x.rank == Rank::Queen || x.suit == Suit::Spade);
+    assert_eq!(g.play(GameTransition::DeclareMisdeal), Err(TransitionError::InvalidMisdeal));
+    for _ in 0..4 {
+        g.play(GameTransition::Bet(3)).unwrap();
+    }
+    assert_eq!(g.play(GameTransition::DeclareMisdeal), Err(TransitionError::InvalidMisdeal));
+}
+
+#[test]
+fn bet_out_of_range() {
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+
+    assert_eq!(g.check(&GameTransition::Bet(-1)), Err(TransitionError::InvalidBet));
+    assert_eq!(g.play(GameTransition::Bet(14)), Err(TransitionError::InvalidBet));
+    assert_eq!(g.check(&GameTransition::Bet(13)), Ok(()));
+    assert_eq!(g.play(GameTransition::Bet(0)), Ok(TransitionSuccess::Bet));
+}
+
+#[test]
+fn check_agrees_with_play() {
+    let mut rng = thread_rng();
+    let deck = cards::new_deck();
+
+    for _ in 0..20 {
+        let mut g = new_game();
+        g.set_misdeal_rule(MisdealRule::NoFaceCards);
+        g.set_manual_round_advance(rng.gen_bool(0.5));
+
+        for _ in 0..2000 {
+            let entry = match rng.gen_range(0usize, 10) {
+                0 => GameTransition::Start,
+                1 => GameTransition::NextRound,
+                2 => GameTransition::DeclareMisdeal,
+                3 | 4 => GameTransition::Bet(rng.gen_range(0usize, 18) as i32 - 2),
+                5 | 6 => GameTransition::Card(rng.choose(&deck).unwrap().clone()),
+                _ => match g.get_current_hand() {
+                    Ok(hand) if !hand.is_empty() => GameTransition::Card(rng.choose(hand).unwrap().clone()),
+                    _ => GameTransition::Bet(3),
+                },
+            };
+
+            let checked = g.check(&entry);
+            let played = g.play(entry).map(|_| ());
+            assert_eq!(checked, played);
+        }
+    }
+}
+
+#[test]
+fn rejected_transitions_leave_game_unchanged() {
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+    deal_with_first_hand(&mut g, |x| x.suit == Suit::Club);
+    let ace_of_clubs = g.player_a.hand.pop().unwrap();
+    let two_of_diamonds = g.player_b.hand.remove(0);
+    g.player_a.hand.insert(0, two_of_diamonds.clone());
+    g.player_b.hand.push(ace_of_clubs.clone());
+
+    let snapshot = format!("{:?}", g);
+    assert_eq!(g.play(GameTransition::Bet(14)), Err(TransitionError::InvalidBet));
+    assert_eq!(g.play(GameTransition::Card(two_of_diamonds.clone())), Err(TransitionError::CardInBettingStage));
+    assert_eq!(g.play(GameTransition::DeclareMisdeal), Err(TransitionError::InvalidMisdeal));
+    assert_eq!(format!("{:?}", g), snapshot);
+
+    for _ in 0..4 {
+        g.play(GameTransition::Bet(3)).unwrap();
+    }
+    assert_eq!(g.play(GameTransition::Card(two_of_diamonds)), Ok(TransitionSuccess::PlayCard));
+
+    let snapshot = format!("{:?}", g);
+    assert_eq!(g.play(GameTransition::Card(ace_of_clubs.clone())), Err(TransitionError::CardIncorrectSuit));
+    assert_eq!(g.play(GameTransition::Card(Card { suit: Suit::Spade, rank: Rank::Ace })), Err(TransitionError::CardNotInHand));
+    assert_eq!(g.play(GameTransition::Bet(3)), Err(TransitionError::BetInTrickStage));
+    assert_eq!(format!("{:?}", g), snapshot);
+
+    assert_eq!(g.get_leading_suit(), Ok(&Suit::Diamond));
+    let diamond = g.player_b.hand[0].clone();
+    assert_eq!(g.play(GameTransition::Card(diamond)), Ok(TransitionSuccess::PlayCard));
+}
+
+/// Places `bets` straight into the scoring, blind nil where `blind_nils` is set, and lets `winner` take every trick of
+/// the round.
+fn score_round_won_by(g: &mut Game, bets: [i32; 4], blind_nils: [bool; 4], winner: usize) {
+    for (seat, (&bet, &blind)) in bets.iter().zip(blind_nils.iter()).enumerate() {
+        g.scoring.add_bet(seat, bet, blind);
+    }
+    g.scoring.bet();
+    let mut pot = [
+        Card { suit: Suit::Club, rank: Rank::Two },
+        Card { suit: Suit::Club, rank: Rank::Three },
+        Card { suit: Suit::Club, rank: Rank::Four },
+        Card { suit: Suit::Club, rank: Rank::Five },
+    ];
+    pot[winner] = Card { suit: Suit::Spade, rank: Rank::Ace };
+    for _ in 0..13 {
+        assert_eq!(g.scoring.trick(winner, &pot), winner);
+    }
+}
+
+#[test]
+fn nil_scored_for_third_seat() {
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+    score_round_won_by(&mut g, [4, 3, 0, 3], [false; 4], 2);
+    assert_eq!(g.scoring.rounds[0].tricks_taken, [0, 0, 13, 0]);
+    assert_eq!(g.scoring.bid_history(2), vec![BidRecord { round: 0, bid: 0, taken: 13, made: false }]);
+    // Team A made 4 with 9 bags, and seat C's nil cost 100.
+    assert_eq!(g.scoring.team_a.cumulative_points, 49 - 100);
+    assert_eq!(g.scoring.team_b.cumulative_points, 0);
+}
+
+#[test]
+fn game_ends_when_either_team_reaches_max_points() {
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+    g.scoring.team_b.cumulative_points = 440;
+    score_round_won_by(&mut g, [3; 4], [false; 4], 1);
+    assert_eq!(g.scoring.team_b.cumulative_points, 507);
+    assert!(g.scoring.is_over);
+
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+    g.scoring.team_a.cumulative_points = 440;
+    score_round_won_by(&mut g, [3; 4], [false; 4], 2);
+    assert_eq!(g.scoring.team_a.cumulative_points, 507);
+    assert!(g.scoring.is_over);
+
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+    g.scoring.team_b.cumulative_points = 420;
+    score_round_won_by(&mut g, [3; 4], [false; 4], 3);
+    assert_eq!(g.scoring.team_b.cumulative_points, 487);
+    assert!(!g.scoring.is_over);
+}
+
+#[test]
+fn projected_outcome() {
+    let mut g = new_game();
+    assert_eq!(g.projected_outcome(), Err(GetError::GameNotStarted));
+    g.play(GameTransition::Start).unwrap();
+    g.play(GameTransition::Bet(3)).unwrap();
+    g.play(GameTransition::Bet(0)).unwrap();
+    g.play(GameTransition::Bet(3)).unwrap();
+    assert_eq!(g.projected_outcome(), Err(GetError::Unknown));
+    g.play(GameTransition::Bet(4)).unwrap();
+
+    g.scoring.team_a.cumulative_points = 450;
+    g.scoring.team_b.cumulative_points = 380;
+    assert_eq!(g.projected_outcome(), Ok(ProjectedOutcome { team_a_score: 510, team_b_score: 520, game_over: true }));
+
+    for trick in 0..8 {
+        g.scoring.team_a.current_round_tricks_won[trick] = 1;
+    }
+    g.scoring.team_b.current_round_tricks_won[8] = 1;
+    g.scoring.nil_check[1] = true;
+    assert_eq!(g.projected_outcome(), Ok(ProjectedOutcome { team_a_score: 512, team_b_score: 320, game_over: true }));
+
+    g.scoring.team_a.cumulative_points = 420;
+    assert_eq!(g.projected_outcome(), Ok(ProjectedOutcome { team_a_score: 482, team_b_score: 320, game_over: false }));
+    assert_eq!(g.scoring.team_a.cumulative_points, 420);
+    assert_eq!(g.scoring.team_a.bags, 0);
+}
+
+#[test]
+#[allow(deprecated)]
+fn get_hand_by_seat() {
+    let mut g = new_game();
+    assert_eq!(g.get_hand(0), Err(GetError::GameNotStarted));
+    assert_eq!(g.get_hand(99), Err(GetError::GameNotStarted));
+
+    g.play(GameTransition::Start).unwrap();
+    assert_eq!(g.get_hand(0), Ok(&g.player_a.hand));
+    assert_eq!(g.get_hand(3), Ok(&g.player_d.hand));
+    assert_eq!(g.get_hand(4), Err(GetError::InvalidSeat));
+    assert_eq!(g.get_hand(99), Err(GetError::InvalidSeat));
+}
+
+#[test]
+fn trick_won_by_leading_suit() {
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+    let mut deck = cards::new_deck();
+    deck.sort();
+    let two_of_clubs = deck.remove(0);
+    let ace_of_spades = deck.pop().unwrap();
+
+    g.player_a.hand = vec![two_of_clubs.clone()];
+    g.player_a.hand.extend(deck.iter().filter(|x| x.suit == Suit::Spade).cloned());
+    g.player_b.hand = deck.iter().filter(|x| x.suit == Suit::Club).cloned().collect();
+    g.player_b.hand.push(ace_of_spades);
+    g.player_c.hand = deck.iter().filter(|x| x.suit == Suit::Diamond).cloned().collect();
+    g.player_d.hand = deck.iter().filter(|x| x.suit == Suit::Heart).cloned().collect();
+    for _ in 0..4 {
+        g.play(GameTransition::Bet(3)).unwrap();
+    }
+
+    g.play(GameTransition::Card(two_of_clubs)).unwrap();
+    g.play(GameTransition::Card(Card { suit: Suit::Club, rank: Rank::Three })).unwrap();
+    g.play(GameTransition::Card(Card { suit: Suit::Diamond, rank: Rank::Ace })).unwrap();
+    assert_eq!(g.play(GameTransition::Card(Card { suit: Suit::Heart, rank: Rank::Ace })), Ok(TransitionSuccess::Trick));
+
+    assert_eq!(g.current_player_index, 1);
+    assert_eq!(g.scoring.team_b.current_round_tricks_won[0], 1);
+    assert_eq!(g.trick_leaders, vec![0]);
+}
+
+#[test]
+fn new_pot_each_round() {
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+    play_round(&mut g).unwrap();
+    assert_eq!(g.hands_played.len(), 14);
+    assert!(g.hands_played[12].iter().all(|x| x.suit != Suit::Blank));
+    assert_eq!(g.hands_played[13], cards::new_pot());
+}
+
+#[test]
+fn review_flags_legal_round() {
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+    play_round(&mut g).unwrap();
+
+    assert_eq!(g.trick_leaders.len(), 13);
+    assert_eq!(g.review_flags(), vec![]);
+}
+
+#[test]
+fn review_flags_renege() {
+    let mut g = new_game();
+    g.hands_played = vec![
+        [
+            Card { suit: Suit::Club, rank: Rank::Two },
+            Card { suit: Suit::Heart, rank: Rank::Three },
+            Card { suit: Suit::Club, rank: Rank::Four },
+            Card { suit: Suit::Club, rank: Rank::Five },
+        ],
+        [
+            Card { suit: Suit::Club, rank: Rank::Seven },
+            Card { suit: Suit::Club, rank: Rank::Eight },
+            Card { suit: Suit::Club, rank: Rank::Nine },
+            Card { suit: Suit::Club, rank: Rank::Six },
+        ],
+    ];
+    g.trick_leaders = vec![0, 3];
+
+    assert_eq!(g.review_flags(), vec![ReviewFlag { seat: 1, round: 0, trick: 1, suit: Suit::Club, void_trick: 0 }]);
+}
+
+#[test]
+fn team_names() {
+    let mut g = new_game();
+    assert_eq!(g.get_team_names(), &[None, None]);
+
+    assert_eq!(g.set_team_name(Team::A, Some("  North South ".to_string())), Ok(()));
+    assert_eq!(g.set_team_name(Team::B, Some("East West".to_string())), Ok(()));
+    assert_eq!(g.get_team_names(), &[Some("North South".to_string()), Some("East West".to_string())]);
+
+    assert_eq!(g.set_team_name(Team::B, None), Ok(()));
+    assert_eq!(g.get_team_names(), &[Some("North South".to_string()), None]);
+}
+
+#[test]
+fn team_names_invalid() {
+    let mut g = new_game();
+    g.set_team_name(Team::A, Some("North South".to_string())).unwrap();
+
+    assert_eq!(g.set_team_name(Team::A, Some("   ".to_string())), Err(NameError::Empty));
+    assert_eq!(g.set_team_name(Team::A, Some("x".repeat(33))), Err(NameError::TooLong));
+    assert_eq!(g.get_team_names(), &[Some("North South".to_string()), None]);
+    assert_eq!(g.set_team_name(Team::A, Some("\u{2664}".repeat(32))), Ok(()));
+    assert_eq!(g.get_team_names(), &[Some("\u{2664}".repeat(32)), None]);
+}
+
+#[test]
+fn state_digest_changes_with_every_transition() {
+    let mut g = new_game();
+    let other = new_game();
+    assert!(g.equivalent(&other));
+    g.set_team_name(Team::A, Some("North South".to_string())).unwrap();
+    assert!(g.equivalent(&other));
+
+    let mut digest = g.state_digest();
+    g.play(GameTransition::Start).unwrap();
+    while g.state != State::Completed {
+        assert_ne!(g.state_digest(), digest);
+        digest = g.state_digest();
+
+        assert!(g.play(GameTransition::Start).is_err());
+        assert_eq!(g.state_digest(), digest);
+
+        if let State::Betting(_) = g.state {
+            g.play(GameTransition::Bet(3)).unwrap();
+        } else {
+            play_valid_card(&mut g).unwrap();
+        }
+    }
+    assert_ne!(g.state_digest(), digest);
+}
+
+#[test]
+fn scores_available_after_start() {
+    let mut g = new_game();
+    assert_eq!(g.get_score(Team::A), Err(GetError::GameNotStarted));
+    assert_eq!(g.get_bags(Team::B), Err(GetError::GameNotStarted));
+
+    g.play(GameTransition::Start).unwrap();
+    assert_eq!(g.get_score(Team::A), Ok(&0));
+    assert_eq!(g.get_bags(Team::B), Ok(&0));
+
+    let g = completed_game();
+    assert_eq!(g.get_score(Team::A), Ok(&g.scoring.team_a.cumulative_points));
+    assert_eq!(g.get_score(Team::B), Ok(&g.scoring.team_b.cumulative_points));
+    assert_eq!(g.get_bags(Team::A), Ok(&g.scoring.team_a.bags));
+    assert_eq!(g.get_bags(Team::B), Ok(&g.scoring.team_b.bags));
+    assert!(g.get_score(Team::A).unwrap() >= &500 || g.get_score(Team::B).unwrap() >= &500);
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_score_getters() {
+    let mut g = new_game();
+    assert_eq!(g.get_team_a_score(), Err(GetError::GameNotStarted));
+
+    g.play(GameTransition::Start).unwrap();
+    g.scoring.team_a.cumulative_points = 120;
+    g.scoring.team_b.bags = 4;
+    assert_eq!(g.get_team_a_score(), g.get_score(Team::A));
+    assert_eq!(g.get_team_b_score(), g.get_score(Team::B));
+    assert_eq!(g.get_team_a_bags(), g.get_bags(Team::A));
+    assert_eq!(g.get_team_b_bags(), Ok(&4));
+}
+
+#[test]
+fn round_history() {
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+    assert_eq!(g.round_history(), &[]);
+    g.scoring.team_a.bags = 3;
+    score_round_won_by(&mut g, [4, 3, 2, 3], [false; 4], 0);
+    assert_eq!(g.round_history(), &[RoundRecord { cumulative_points: [67 - 100, 0], bags_taken: [7, 0], tricks_taken: [13, 0, 0, 0] }]);
+}
+
+#[test]
+fn bags_remaining_until_penalty() {
+    let mut g = new_game();
+    assert_eq!(g.bags_remaining_until_penalty(Team::A), Err(GetError::GameNotStarted));
+
+    g.play(GameTransition::Start).unwrap();
+    assert_eq!(g.bags_remaining_until_penalty(Team::A), Ok(10));
+    g.scoring.team_a.bags = 9;
+    assert_eq!(g.bags_remaining_until_penalty(Team::A), Ok(1));
+    assert_eq!(g.bags_remaining_until_penalty(Team::B), Ok(10));
+
+    g.scoring.team_a.bags = 3;
+    score_round_won_by(&mut g, [4, 3, 2, 3], [false; 4], 0);
+    assert_eq!(g.scoring.rounds.last().unwrap().bags_taken, [7, 0]);
+    assert_eq!(g.scoring.team_a.bags, 0);
+    assert_eq!(g.scoring.team_a.cumulative_points, 67 - 100);
+    assert_eq!(g.bags_remaining_until_penalty(Team::A), Ok(10));
+
+    g.scoring.team_a.bags = 9;
+    score_round_won_by(&mut g, [3, 3, 3, 3], [false; 4], 0);
+    assert_eq!(g.scoring.rounds.last().unwrap().bags_taken, [7, 0]);
+    assert_eq!(g.scoring.team_a.bags, 6);
+    assert_eq!(g.bags_remaining_until_penalty(Team::A), Ok(4));
+
+    g.scoring.team_a.bags = 9;
+    score_round_won_by(&mut g, [1, 3, 1, 3], [false; 4], 0);
+    assert_eq!(g.scoring.rounds.last().unwrap().bags_taken, [11, 0]);
+    assert_eq!(g.scoring.team_a.bags, 0);
+    assert_eq!(g.bags_remaining_until_penalty(Team::A), Ok(10));
+}
+
+#[test]
+fn bag_penalty_for_each_threshold_crossed() {
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+    g.scoring.team_a.bags = 9;
+    score_round_won_by(&mut g, [1, 3, 0, 3], [false; 4], 0);
+    assert_eq!(g.scoring.rounds.last().unwrap().bags_taken, [12, 0]);
+    // Made 1 with 12 bags and seat C made nil, but the 21 bags cross the threshold twice.
+    assert_eq!(g.scoring.team_a.cumulative_points, 22 + 100 - 200);
+    assert_eq!(g.scoring.team_a.bags, 1);
+    assert_eq!(g.bags_remaining_until_penalty(Team::A), Ok(9));
+}
+
+#[test]
+fn blind_nil_after_revealing_hand() {
+    let mut g = new_game();
+    let player_a = g.player_a.id;
+    assert_eq!(g.blind_nil_available(player_a), Err(GetError::GameNotStarted));
+
+    g.play(GameTransition::Start).unwrap();
+    assert_eq!(g.blind_nil_available(player_a), Ok(true));
+    assert_eq!(g.blind_nil_available(uuid::Uuid::new_v4()), Err(GetError::InvalidUuid));
+
+    g.get_hand_by_player_id(player_a).unwrap();
+    g.get_current_hand().unwrap();
+    assert_eq!(g.blind_nil_available(player_a), Ok(true));
+    assert_eq!(g.reveal_hand(uuid::Uuid::new_v4()), Err(GetError::InvalidUuid));
+    let hand = g.player_a.hand.clone();
+    assert_eq!(g.reveal_hand(player_a), Ok(&hand));
+    assert_eq!(g.blind_nil_available(player_a), Ok(false));
+    assert_eq!(g.check(&GameTransition::BlindNil), Err(TransitionError::HandRevealed));
+    assert_eq!(g.play(GameTransition::BlindNil), Err(TransitionError::HandRevealed));
+    assert_eq!(g.state, State::Betting(0));
+    assert_eq!(g.play(GameTransition::Bet(0)), Ok(TransitionSuccess::Bet));
+
+    let player_b = g.player_b.id;
+    assert_eq!(g.blind_nil_available(player_b), Ok(true));
+    g.reveal_hand(player_b).unwrap();
+    assert_eq!(g.blind_nil_available(player_b), Ok(false));
+    assert_eq!(g.play(GameTransition::BlindNil), Err(TransitionError::HandRevealed));
+
+    assert_eq!(g.blind_nil_available(g.player_c.id), Ok(true));
+    assert_eq!(g.play(GameTransition::Bet(3)), Ok(TransitionSuccess::Bet));
+    assert_eq!(g.blind_nil_available(player_b), Ok(false));
+}
+
+#[test]
+fn blind_nil_available_again_after_deal() {
+    let mut g = new_game();
+    g.set_manual_round_advance(true);
+    g.play(GameTransition::Start).unwrap();
+    let player_a = g.player_a.id;
+    g.reveal_hand(player_a).unwrap();
+    play_round(&mut g).unwrap();
+    assert!(g.player_a.hand_revealed);
+    assert_eq!(g.blind_nil_available(player_a), Ok(false));
+
+    g.play(GameTransition::NextRound).unwrap();
+    assert_eq!(g.blind_nil_available(player_a), Ok(true));
+    assert_eq!(g.play(GameTransition::BlindNil), Ok(TransitionSuccess::Bet));
+}
+
+#[test]
+fn blind_nil_scoring() {
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+    assert_eq!(g.play(GameTransition::BlindNil), Ok(TransitionSuccess::Bet));
+    let player_a = g.player_a.id;
+    assert!(g.reveal_hand(player_a).is_ok());
+    for _ in 0..3 {
+        g.play(GameTransition::Bet(3)).unwrap();
+    }
+    assert_eq!(g.state, State::Trick(0));
+    assert_eq!(g.scoring.bets_placed[0], [0, 3, 3, 3]);
+    assert_eq!(g.scoring.blind_nils[0], [true, false, false, false]);
+
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+    score_round_won_by(&mut g, [0, 3, 3, 3], [true, false, false, false], 1);
+    assert_eq!(g.scoring.team_a.cumulative_points, 200);
+    assert_eq!(g.scoring.team_b.cumulative_points, 67);
+
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+    score_round_won_by(&mut g, [0, 3, 3, 3], [true, false, false, false], 0);
+    assert_eq!(g.scoring.team_a.cumulative_points, 40 - 100 - 200);
+    assert_eq!(g.scoring.team_b.cumulative_points, 0);
+}
+
+#[test]
+fn team_of_seat() {
+    assert_eq!(Team::of_seat(0), Team::A);
+    assert_eq!(Team::of_seat(1), Team::B);
+    assert_eq!(Team::of_seat(2), Team::A);
+    assert_eq!(Team::of_seat(3), Team::B);
+    for seat in 0..4 {
+        assert_eq!(Team::of_seat(Team::partner_of(seat)), Team::of_seat(seat));
+        assert_eq!(Team::partner_of(Team::partner_of(seat)), seat);
+    }
+    assert_eq!(Team::partner_of(1), 3);
+    assert_eq!(Team::A.to_string(), "Team A");
+    assert_eq!(format!("{}", Team::B), "Team B");
+}
+
+#[test]
+#[should_panic]
+fn team_of_invalid_seat() {
+    Team::of_seat(4);
+}
+
+#[test]
+#[allow(deprecated)]
+fn get_winner() {
+    let mut g = new_game();
+    assert_eq!(g.get_winner(), Err(GetError::GameNotCompleted));
+    g.play(GameTransition::Start).unwrap();
+    assert_eq!(g.get_winner(), Err(GetError::GameNotCompleted));
+    assert_eq!(g.get_winner_ids(), Err(GetError::GameNotCompleted));
+
+    g.state = State::Completed;
+    g.completion_reason = Some(CompletionReason::Points);
+    g.scoring.team_a.cumulative_points = 510;
+    g.scoring.team_b.cumulative_points = 430;
+    assert_eq!(g.get_winner(), Ok(GameOutcome { winner: Some(Team::A), scores: [510, 430], reason: CompletionReason::Points }));
+    assert_eq!(g.get_winner_ids(), Ok((&g.player_a.id, &g.player_c.id)));
+
+    g.scoring.team_b.cumulative_points = 520;
+    assert_eq!(g.get_winner(), Ok(GameOutcome { winner: Some(Team::B), scores: [510, 520], reason: CompletionReason::Points }));
+    assert_eq!(g.get_winner_ids(), Ok((&g.player_b.id, &g.player_d.id)));
+
+    g.scoring.team_b.cumulative_points = 510;
+    assert_eq!(g.get_winner(), Ok(GameOutcome { winner: None, scores: [510, 510], reason: CompletionReason::Points }));
+    assert_eq!(g.get_winner_ids(), Err(GetError::Unknown));
+}
+
+#[test]
+fn completion_reason_points() {
+    let mut g = new_game();
+    assert_eq!(g.completion_reason, None);
+    g.set_manual_round_advance(true);
+    g.play(GameTransition::Start).unwrap();
+    play_round(&mut g).unwrap();
+    assert_eq!(g.completion_reason, None);
+
+    let g = completed_game();
+    assert_eq!(g.completion_reason, Some(CompletionReason::Points));
+    assert_eq!(g.get_winner().unwrap().reason, CompletionReason::Points);
+}
+
+#[test]
+fn hand_sort_per_player() {
+    let mut g = new_game();
+    g.set_manual_round_advance(true);
+    let (player_a, player_b) = (g.player_a.id, g.player_b.id);
+    assert_eq!(g.set_hand_sort(player_a, HandSortOrder::ByRank), Ok(()));
+    assert_eq!(g.set_hand_sort(uuid::Uuid::new_v4(), HandSortOrder::ByRank), Err(GetError::InvalidUuid));
+    g.play(GameTransition::Start).unwrap();
+
+    for round in 0..2 {
+        let hand_a = g.get_hand_by_player_id(player_a).unwrap().clone();
+        assert!(hand_a.windows(2).all(|w| (w[0].rank as u8, w[0].suit as u8) < (w[1].rank as u8, w[1].suit as u8)));
+
+        let hand_b = g.get_hand_by_player_id(player_b).unwrap().clone();
+        if round == 0 {
+            assert!(hand_b.windows(2).all(|w| w[0] < w[1]));
+            assert_eq!(g.set_hand_sort(player_b, HandSortOrder::BySuitDescending), Ok(()));
+            let resorted = g.get_hand_by_player_id(player_b).unwrap();
+            assert!(resorted.windows(2).all(|w| w[0] > w[1]));
+        } else {
+            assert!(hand_b.windows(2).all(|w| w[0] > w[1]));
+        }
+
+        play_round(&mut g).unwrap();
+        g.play(GameTransition::NextRound).unwrap();
+    }
+}
+
+#[test]
+fn hand_sort_keeps_digest() {
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+    let digest = g.state_digest();
+    let player_c = g.player_c.id;
+    g.set_hand_sort(player_c, HandSortOrder::BySuitDescending).unwrap();
+    assert_eq!(g.state_digest(), digest);
+}
+
+#[test]
+fn reveal_hand_changes_digest() {
+    let mut g = new_game();
+    g.play(GameTransition::Start).unwrap();
+    let digest = g.state_digest();
+    let player_c = g.player_c.id;
+    g.get_hand_by_player_id(player_c).unwrap();
+    assert_eq!(g.state_digest(), digest);
+
+    g.reveal_hand(player_c).unwrap();
+    assert_ne!(g.state_digest(), digest);
+    let revealed = g.state_digest();
+    g.reveal_hand(player_c).unwrap();
+    assert_eq!(g.state_digest(), revealed);
+}
+
+/// Plays a round of the given bets, returning the tricks each seat took according to the trick history.
+fn play_scripted_round(g: &mut Game, bets: [i32; 4]) -> [i32; 4] {
+    let first_trick = g.trick_leaders.len();
+    for bet in bets.iter() {
+        g.play(GameTransition::Bet(*bet)).unwrap();
+    }
+    for _ in 0..52 {
+        play_valid_card(g).unwrap();
+    }
+
+    let mut taken = [0; 4];
+    for (cards, &leader) in g.hands_played[first_trick..].iter().zip(&g.trick_leaders[first_trick..]) {
+        taken[cards::get_trick_winner(leader, cards)] += 1;
+    }
+    taken
+}
+
+#[test]
+fn bid_history_two_rounds() {
+    let mut g = new_game();
+    let ids = [g.player_a.id, g.player_b.id, g.player_c.id, g.player_d.id];
+    assert_eq!(g.bid_history(ids[0]), Ok(vec![]));
+    assert_eq!(g.bid_history(uuid::Uuid::new_v4()), Err(GetError::InvalidUuid));
+
+    g.play(GameTransition::Start).unwrap();
+    let rounds = [[3, 0, 4, 2], [1, 5, 0, 3]];
+    let taken = [play_scripted_round(&mut g, rounds[0]), play_scripted_round(&mut g, rounds[1])];
+    assert_eq!(g.state, State::Betting(0));
+
+    for (seat, id) in ids.iter().enumerate() {
+        let history = g.bid_history(*id).unwrap();
+        assert_eq!(history.len(), 2);
+        for (round, record) in history.iter().enumerate() {
+            let bid = rounds[round][seat];
+            let made = if bid == 0 { taken[round][seat] == 0 } else { taken[round][seat] >= bid };
+            assert_eq!(*record, BidRecord { round, bid, taken: taken[round][seat], made });
+        }
+    }
+    assert_eq!(taken[0].iter().sum::<i32>(), 13);
+    assert_eq!(g.scoring.tricks_won, [0; 4]);
+}
+
+#[test]
+fn game_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Game>();
+}
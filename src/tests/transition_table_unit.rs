@@ -0,0 +1,151 @@
+use super::super::cards::{Card, Suit, Rank};
+use super::super::{Game, GameTransition, TransitionKind};
+use super::super::game_state::{State, StateKind, MisdealRule};
+use super::spades_game_api_unit::{new_game, play_valid_card, play_round, deal_with_first_hand};
+
+/// The same table is rendered into the docs of `Game::play`.
+const TABLE: &str = include_str!("../transitions.md");
+
+const STATES: [StateKind; 5] = [StateKind::NotStarted, StateKind::Betting, StateKind::Trick, StateKind::RoundOver, StateKind::Completed];
+const TRANSITIONS: [TransitionKind; 6] = [TransitionKind::Start, TransitionKind::Bet, TransitionKind::Card, TransitionKind::NextRound, TransitionKind::DeclareMisdeal, TransitionKind::BlindNil];
+
+/// Returns the kind's position in `STATES`. Adding a variant stops this compiling until the variant has an index, the
+/// reminder to add it to `STATES` and the table. `kinds_match_variants` checks `STATES` against these indices.
+fn state_index(kind: StateKind) -> usize {
+    match kind {
+        StateKind::NotStarted => 0,
+        StateKind::Betting => 1,
+        StateKind::Trick => 2,
+        StateKind::RoundOver => 3,
+        StateKind::Completed => 4,
+    }
+}
+
+/// Returns the kind's position in `TRANSITIONS`, the same way as `state_index`.
+fn transition_index(kind: TransitionKind) -> usize {
+    match kind {
+        TransitionKind::Start => 0,
+        TransitionKind::Bet => 1,
+        TransitionKind::Card => 2,
+        TransitionKind::NextRound => 3,
+        TransitionKind::DeclareMisdeal => 4,
+        TransitionKind::BlindNil => 5,
+    }
+}
+
+/// Returns the name used in the table.
+fn state_name(kind: StateKind) -> &'static str {
+    match kind {
+        StateKind::NotStarted => "NotStarted",
+        StateKind::Betting => "Betting",
+        StateKind::Trick => "Trick",
+        StateKind::RoundOver => "RoundOver",
+        StateKind::Completed => "Completed",
+    }
+}
+
+fn transition_name(kind: TransitionKind) -> &'static str {
+    match kind {
+        TransitionKind::Bet => "Bet",
+        TransitionKind::Card => "Card",
+        TransitionKind::Start => "Start",
+        TransitionKind::NextRound => "NextRound",
+        TransitionKind::DeclareMisdeal => "DeclareMisdeal",
+        TransitionKind::BlindNil => "BlindNil",
+    }
+}
+
+/// Returns a game in the given stage, with manual round advance and a misdeal the first seat can declare.
+fn sample_game(kind: StateKind) -> Game {
+    let mut g = new_game();
+    g.set_manual_round_advance(true);
+    g.set_misdeal_rule(MisdealRule::NoSpades);
+    if kind == StateKind::NotStarted {
+        return g;
+    }
+
+    g.play(GameTransition::Start).unwrap();
+    deal_with_first_hand(&mut g, |card| card.suit != Suit::Spade);
+    match kind {
+        StateKind::NotStarted | StateKind::Betting => {},
+        StateKind::Trick => {
+            for _ in 0..4 {
+                g.play(GameTransition::Bet(3)).unwrap();
+            }
+        },
+        StateKind::RoundOver => {
+            play_round(&mut g).unwrap();
+        },
+        StateKind::Completed => {
+            while g.state != State::Completed {
+                match g.state {
+                    State::Betting(_) => { g.play(GameTransition::Bet(3)).unwrap(); },
+                    State::RoundOver => { g.play(GameTransition::NextRound).unwrap(); },
+                    _ => { play_valid_card(&mut g).unwrap(); },
+                }
+            }
+        },
+    }
+    assert_eq!(g.state.kind(), kind);
+    g
+}
+
+/// Returns a legal argument for the transition where the stage allows one.
+fn sample_transition(g: &Game, kind: TransitionKind) -> GameTransition {
+    match kind {
+        TransitionKind::Bet => GameTransition::Bet(3),
+        TransitionKind::Card => match g.get_current_hand() {
+            Ok(hand) if !hand.is_empty() => GameTransition::Card(hand[0].clone()),
+            _ => GameTransition::Card(Card { suit: Suit::Spade, rank: Rank::Ace }),
+        },
+        TransitionKind::Start => GameTransition::Start,
+        TransitionKind::NextRound => GameTransition::NextRound,
+        TransitionKind::DeclareMisdeal => GameTransition::DeclareMisdeal,
+        TransitionKind::BlindNil => GameTransition::BlindNil,
+    }
+}
+
+fn cells(row: &str) -> Vec<String> {
+    row.trim().trim_matches('|').split('|').map(|cell| cell.trim().trim_matches('`').to_string()).collect()
+}
+
+#[test]
+fn kinds_match_variants() {
+    assert_eq!(State::Betting(2).kind(), StateKind::Betting);
+    assert_eq!(State::Trick(0).kind(), StateKind::Trick);
+    assert_eq!(GameTransition::Bet(4).kind(), TransitionKind::Bet);
+    assert_eq!(GameTransition::Card(Card { suit: Suit::Heart, rank: Rank::Two }).kind(), TransitionKind::Card);
+    for (i, &state) in STATES.iter().enumerate() {
+        assert_eq!(state_index(state), i);
+    }
+    for (i, &transition) in TRANSITIONS.iter().enumerate() {
+        assert_eq!(transition_index(transition), i);
+    }
+}
+
+#[test]
+fn transition_table() {
+    let mut rows = TABLE.lines().filter(|line| !line.trim().is_empty());
+    let header = cells(rows.next().unwrap());
+    rows.next().unwrap();
+    let rows: Vec<Vec<String>> = rows.map(cells).collect();
+
+    let expected_header: Vec<&str> = TRANSITIONS.iter().map(|&kind| transition_name(kind)).collect();
+    assert_eq!(header[1..].to_vec(), expected_header);
+    assert_eq!(rows.len(), STATES.len());
+
+    for (row, &state) in rows.iter().zip(STATES.iter()) {
+        assert_eq!(row[0], state_name(state));
+        assert_eq!(row.len(), header.len());
+        for (cell, &transition) in row[1..].iter().zip(TRANSITIONS.iter()) {
+            let mut g = sample_game(state);
+            let entry = sample_transition(&g, transition);
+            assert_eq!(entry.kind(), transition);
+            let outcome = match g.play(entry) {
+                Ok(_) => "Ok".to_string(),
+                Err(e) => format!("Err({:?})", e),
+            };
+            assert_eq!(&outcome, cell, "{:?} in {:?}", transition, state);
+        }
+    }
+}
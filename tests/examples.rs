@@ -0,0 +1,49 @@
+extern crate spades;
+extern crate uuid;
+
+use std::io::Cursor;
+use spades::State;
+
+#[path = "../examples/local_game.rs"]
+#[allow(dead_code)]
+mod local_game;
+#[path = "../examples/bot_match.rs"]
+#[allow(dead_code)]
+mod bot_match;
+
+#[test]
+fn local_game_completes_a_round() {
+    // Every number below 13 is a valid bet, and any 13 lines in a row try every index of the hand, so one of them
+    // follows suit. That bounds a round at 4 bets and 13 lines for each of the 52 cards.
+    let input: String = (0..4 + 52 * 13).map(|i| format!("{}\n", i % 13)).collect();
+    let mut output = vec![];
+    let g = local_game::run(Cursor::new(input), &mut output).unwrap();
+
+    assert!(g.to_notation().contains("Round 1\nBids "));
+    assert!(String::from_utf8(output).unwrap().contains("Card to play (0-12): "));
+}
+
+#[test]
+fn local_game_stops_at_end_of_input() {
+    let mut output = vec![];
+    let g = local_game::run(Cursor::new("3\nnot a bet\n"), &mut output).unwrap();
+
+    assert_eq!(*g.get_state(), State::Betting(1));
+    assert!(String::from_utf8(output).unwrap().contains("Please enter a number."));
+}
+
+#[test]
+fn bot_match_plays_to_completion() {
+    let mut output = vec![];
+    let g = bot_match::run(500, &mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+
+    assert_eq!(*g.get_state(), State::Completed);
+    assert!(output.starts_with("Round 1: "));
+    let outcome = g.get_winner().unwrap();
+    let last = output.lines().last().unwrap();
+    match outcome.winner {
+        Some(team) => assert!(last.starts_with(&format!("{} wins ", team))),
+        None => assert_eq!(last, format!("Tied at {}", outcome.scores[0])),
+    }
+}
@@ -15,7 +15,7 @@ fn main() {
          uuid::Uuid::new_v4(), 
          uuid::Uuid::new_v4(), 
          uuid::Uuid::new_v4()], 
-         500);
+         500).unwrap();
 
     g.play(GameTransition::Start);
     println!("{:#?}", g);
@@ -60,11 +60,11 @@ fn main() {
     //println!("{:#?}", g);
 }
 
-pub fn get_valid_card_index(leading_suit: Suit, hand: &Vec<Card>) -> usize {
-    if hand.iter().any(|ref x| x.suit == leading_suit) && leading_suit != Suit::Blank {
-        return hand.iter().position(|ref x| x.suit == leading_suit).unwrap();
+pub fn get_valid_card_index(leading_suit: Suit, hand: &[Card]) -> usize {
+    if hand.iter().any(|x| x.suit == leading_suit) && leading_suit != Suit::Blank {
+        hand.iter().position(|x| x.suit == leading_suit).unwrap()
     } else {
-        return 0;
+        0
     }
     
 }